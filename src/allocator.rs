@@ -1,8 +1,38 @@
-use crate::internal::{error_helper, length};
-use std::alloc::Layout;
+use crate::{Error, internal::length};
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+fn next_generation() -> usize {
+    GENERATION.fetch_add(1, Ordering::Relaxed)
+}
+
+struct Global;
+
+unsafe impl GlobalAlloc for Global {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { std::alloc::alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { std::alloc::dealloc(ptr, layout) }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Savepoint {
+    generation: usize,
+    offset: usize,
+}
 
 pub struct Allocator {
+    backend: Box<dyn GlobalAlloc + Send>,
+    generation: usize,
     allocated: bool,
+    external: bool,
     buffer: *mut u8,
     offset: usize,
     bounds: usize,
@@ -11,11 +41,18 @@ pub struct Allocator {
 
 impl Allocator {
     const ANCHOR_SIZE: usize = 4;
-    const ANCHOR_SHRINK_LIMITS: usize = 16;
+    const ANCHOR_SHRINK_LIMITS: usize = 127;
 
     pub fn new() -> Self {
+        Self::with_backend(Global)
+    }
+
+    pub fn with_backend<B: GlobalAlloc + Send + 'static>(backend: B) -> Self {
         Self {
+            backend: Box::new(backend),
+            generation: next_generation(),
             allocated: false,
+            external: false,
             buffer: std::ptr::null_mut(),
             offset: 0,
             bounds: 0,
@@ -23,6 +60,20 @@ impl Allocator {
         }
     }
 
+    pub(crate) unsafe fn with_external_buffer(buffer: *mut u8, length: usize) -> Self {
+        let bounds = length.min(i32::MAX as usize);
+        Self {
+            backend: Box::new(Global),
+            generation: next_generation(),
+            allocated: true,
+            external: true,
+            buffer: buffer,
+            offset: 0,
+            bounds: bounds,
+            limits: bounds,
+        }
+    }
+
     pub fn length(&self) -> usize {
         self.offset
     }
@@ -35,17 +86,20 @@ impl Allocator {
         self.limits
     }
 
-    fn resize(&mut self, length: usize) -> Result<(), Box<dyn std::error::Error>> {
+    fn resize(&mut self, length: usize) -> Result<(), Error> {
         assert!(self.limits <= i32::MAX as usize);
         assert!(self.bounds <= self.limits);
         assert!(self.offset <= self.bounds);
         assert!(length != 0);
+        if self.external {
+            return Err(Error::NotEnoughBytesToWrite);
+        }
 
         let offset = self.offset;
         let limits = self.limits;
         let amount = offset as u64 + length as u64;
         if length > i32::MAX as usize || amount > limits as u64 {
-            return Err(error_helper::error_allocator_max_capacity_overflow());
+            return Err(Error::MaxCapacityOverflow);
         }
 
         let source = self.bounds;
@@ -63,13 +117,14 @@ impl Allocator {
         assert!(cursor <= self.limits as u64);
 
         let bounds = cursor as usize;
-        let target = unsafe { std::alloc::alloc(Layout::from_size_align(bounds, 1).unwrap()) };
+        let target = unsafe { self.backend.alloc(Layout::from_size_align(bounds, 1).unwrap()) };
         if target.is_null() {
-            return Err(error_helper::error_allocator_allocate_failed());
+            return Err(Error::AllocationFailed);
         }
         if self.allocated {
             assert!(self.buffer.is_null() == false);
-            unsafe { std::alloc::dealloc(self.buffer, Layout::from_size_align(self.bounds, 1).unwrap()) };
+            unsafe { std::ptr::copy_nonoverlapping(self.buffer, target, offset) };
+            unsafe { self.backend.dealloc(self.buffer, Layout::from_size_align(self.bounds, 1).unwrap()) };
         }
         self.allocated = true;
         self.buffer = target;
@@ -79,7 +134,7 @@ impl Allocator {
         Ok(())
     }
 
-    pub fn ensure(&mut self, length: usize) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn ensure(&mut self, length: usize) -> Result<(), Error> {
         assert!(self.bounds <= i32::MAX as usize);
         assert!(self.offset <= self.bounds);
         if length > i32::MAX as usize || self.offset as u64 + length as u64 > self.bounds as u64 {
@@ -90,7 +145,7 @@ impl Allocator {
         Ok(())
     }
 
-    pub(crate) fn assign(&mut self, length: usize) -> Result<*mut u8, Box<dyn std::error::Error>> {
+    pub(crate) fn assign(&mut self, length: usize) -> Result<*mut u8, Error> {
         assert!(length != 0);
         self.ensure(length)?;
         let offset = self.offset;
@@ -98,30 +153,43 @@ impl Allocator {
         Ok(unsafe { self.buffer.add(offset) })
     }
 
-    pub(crate) fn anchor(&mut self) -> Result<usize, Box<dyn std::error::Error>> {
-        self.ensure(Self::ANCHOR_SIZE)?;
+    fn anchor_size(&self) -> usize {
+        if self.external { 1 } else { Self::ANCHOR_SIZE }
+    }
+
+    pub(crate) fn anchor(&mut self) -> Result<usize, Error> {
+        let size = self.anchor_size();
+        self.ensure(size)?;
         let offset = self.offset;
-        self.offset = offset + Self::ANCHOR_SIZE;
+        self.offset = offset + size;
         Ok(offset)
     }
 
-    pub(crate) fn finish_anchor(&mut self, anchor: usize) -> Result<(), Box<dyn std::error::Error>> {
+    pub(crate) fn finish_anchor(&mut self, anchor: usize) -> Result<(), Error> {
         assert!(self.bounds <= i32::MAX as usize);
         assert!(self.offset <= self.bounds);
         let offset = self.offset;
-        let refers = anchor as u64 + Self::ANCHOR_SIZE as u64;
+        let size = self.anchor_size();
+        let refers = anchor as u64 + size as u64;
         if anchor > i32::MAX as usize || refers > offset as u64 {
-            return Err(error_helper::error_allocator_invalid());
+            return Err(Error::InvalidAllocator);
         }
         let length = offset - refers as usize;
-        let target = unsafe { self.buffer.add(anchor) };
         if length <= Self::ANCHOR_SHRINK_LIMITS {
-            self.offset = offset - 3;
+            let target = unsafe { self.buffer.add(anchor) };
+            self.offset = offset - (size - 1);
             unsafe { length::encode_length_prefix(target, length, 1) };
-            unsafe { std::ptr::copy(target.add(4), target.add(1), length) };
+            unsafe { std::ptr::copy(target.add(size), target.add(1), length) };
             assert!(self.offset >= 1);
             assert!(self.offset <= self.bounds);
         } else {
+            if size != Self::ANCHOR_SIZE {
+                self.ensure(Self::ANCHOR_SIZE - size)?;
+                let target = unsafe { self.buffer.add(anchor) };
+                unsafe { std::ptr::copy(target.add(size), target.add(Self::ANCHOR_SIZE), length) };
+                self.offset = offset + (Self::ANCHOR_SIZE - size);
+            }
+            let target = unsafe { self.buffer.add(anchor) };
             unsafe { length::encode_length_prefix(target, length, 4) };
             assert!(self.offset >= 4);
             assert!(self.offset <= self.bounds);
@@ -129,7 +197,33 @@ impl Allocator {
         Ok(())
     }
 
-    pub fn append(&mut self, span: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    pub(crate) fn finish_anchor_length(length: usize) -> usize {
+        if length <= Self::ANCHOR_SHRINK_LIMITS { 1 } else { 4 }
+    }
+
+    pub fn clear(&mut self) {
+        assert!(self.offset <= self.bounds);
+        self.generation = next_generation();
+        self.offset = 0;
+    }
+
+    pub fn savepoint(&self) -> Savepoint {
+        Savepoint {
+            generation: self.generation,
+            offset: self.offset,
+        }
+    }
+
+    pub fn rollback(&mut self, savepoint: Savepoint) -> Result<(), Error> {
+        assert!(self.offset <= self.bounds);
+        if savepoint.generation != self.generation || savepoint.offset > self.offset {
+            return Err(Error::InvalidAllocator);
+        }
+        self.offset = savepoint.offset;
+        Ok(())
+    }
+
+    pub fn append(&mut self, span: &[u8]) -> Result<(), Error> {
         if span.is_empty() {
             return Ok(());
         }
@@ -140,13 +234,14 @@ impl Allocator {
     }
 }
 
+unsafe impl Send for Allocator {}
+
 impl Drop for Allocator {
     fn drop(&mut self) {
-        if self.allocated {
-            assert!(self.offset != 0);
+        if self.allocated && self.external == false {
             assert!(self.bounds >= self.offset);
             assert!(self.buffer.is_null() == false);
-            unsafe { std::alloc::dealloc(self.buffer, Layout::from_size_align(self.bounds, 1).unwrap()) };
+            unsafe { self.backend.dealloc(self.buffer, Layout::from_size_align(self.bounds, 1).unwrap()) };
         }
     }
 }
@@ -164,7 +259,7 @@ where
     type Output = Index::Output;
 
     fn index(&self, index: Index) -> &Self::Output {
-        if self.offset == 0 {
+        if self.allocated == false {
             assert!(self.buffer.is_null());
             return &[][index];
         } else {
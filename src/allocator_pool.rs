@@ -0,0 +1,77 @@
+use crate::allocator::Allocator;
+use std::sync::Mutex;
+
+pub struct AllocatorPool {
+    allocators: Mutex<Vec<Allocator>>,
+    max_pooled: usize,
+    max_retained_capacity: usize,
+}
+
+impl AllocatorPool {
+    pub fn new(max_pooled: usize, max_retained_capacity: usize) -> Self {
+        Self {
+            allocators: Mutex::new(Vec::new()),
+            max_pooled: max_pooled,
+            max_retained_capacity: max_retained_capacity,
+        }
+    }
+
+    pub fn max_pooled(&self) -> usize {
+        self.max_pooled
+    }
+
+    pub fn max_retained_capacity(&self) -> usize {
+        self.max_retained_capacity
+    }
+
+    pub fn pooled(&self) -> usize {
+        self.allocators.lock().map_or(0, |allocators| allocators.len())
+    }
+
+    pub fn rent(&self) -> PooledAllocator<'_> {
+        let allocator = self.allocators.lock().ok().and_then(|mut allocators| allocators.pop());
+        PooledAllocator {
+            pool: self,
+            allocator: Some(allocator.unwrap_or_else(Allocator::new)),
+        }
+    }
+
+    fn restore(&self, mut allocator: Allocator) {
+        if allocator.capacity() > self.max_retained_capacity {
+            return;
+        }
+        allocator.clear();
+        if let Ok(mut allocators) = self.allocators.lock()
+            && allocators.len() < self.max_pooled
+        {
+            allocators.push(allocator);
+        }
+    }
+}
+
+pub struct PooledAllocator<'a> {
+    pool: &'a AllocatorPool,
+    allocator: Option<Allocator>,
+}
+
+impl std::ops::Deref for PooledAllocator<'_> {
+    type Target = Allocator;
+
+    fn deref(&self) -> &Self::Target {
+        self.allocator.as_ref().unwrap()
+    }
+}
+
+impl std::ops::DerefMut for PooledAllocator<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.allocator.as_mut().unwrap()
+    }
+}
+
+impl Drop for PooledAllocator<'_> {
+    fn drop(&mut self) {
+        if let Some(allocator) = self.allocator.take() {
+            self.pool.restore(allocator);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{Error, converter, converter::Converter, decode_limits};
+
+pub(crate) fn decode_zero_sized_count(span: &mut &[u8]) -> Result<usize, Error> {
+    let count = converter::decode(span)?;
+    decode_limits::ensure_collection_length(count)?;
+    Ok(count)
+}
+
+pub struct CollectionReader<'a, E> {
+    span: &'a [u8],
+    length: usize,
+    index: usize,
+    remaining: Option<usize>,
+    converter: &'a dyn Converter<E>,
+    pending: Option<Error>,
+    failed: bool,
+}
+
+impl<'a, E> CollectionReader<'a, E> {
+    pub fn new(converter: &'a dyn Converter<E>, span: &'a [u8]) -> Self {
+        let mut body = span;
+        let mut remaining = None;
+        let mut pending = None;
+        if converter.is_zero_sized() {
+            match decode_zero_sized_count(&mut body) {
+                Ok(count) => remaining = Some(count),
+                Err(error) => pending = Some(error.with_offset(0)),
+            }
+        }
+        Self {
+            span: body,
+            length: span.len(),
+            index: 0,
+            remaining: remaining,
+            converter: converter,
+            pending: pending,
+            failed: false,
+        }
+    }
+
+    pub fn with_length_prefix(converter: &'a dyn Converter<E>, span: &mut &'a [u8]) -> Result<Self, Error> {
+        let body = converter::decode_with_length_prefix(span).map_err(|error| error.with_offset(0))?;
+        Ok(Self::new(converter, body))
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn remaining(&self) -> &'a [u8] {
+        self.span
+    }
+
+    fn is_finished(&self) -> bool {
+        if self.failed {
+            return true;
+        }
+        if self.pending.is_some() {
+            return false;
+        }
+        match self.remaining {
+            Some(remaining) => remaining == 0,
+            None => self.span.is_empty(),
+        }
+    }
+
+    fn fail(&mut self, error: Error, offset: usize) -> Error {
+        self.failed = true;
+        error.with_index(self.index).with_offset(offset)
+    }
+
+    fn advance(&mut self) {
+        self.index += 1;
+        if let Some(remaining) = self.remaining {
+            self.remaining = Some(remaining - 1);
+        }
+    }
+
+    fn skip_one(&mut self) -> Result<(), Error> {
+        let offset = self.length - self.span.len();
+        let result = converter::skip_auto(self.converter, &mut self.span).map_err(|error| error.with_offset(0));
+        match result {
+            Ok(()) => {
+                self.advance();
+                Ok(())
+            }
+            Err(error) => Err(self.fail(error, offset)),
+        }
+    }
+}
+
+impl<'a, E> Iterator for CollectionReader<'a, E> {
+    type Item = Result<E, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished() {
+            return None;
+        }
+        if let Some(error) = self.pending.take() {
+            self.failed = true;
+            return Some(Err(error));
+        }
+        let offset = self.length - self.span.len();
+        match self.converter.decode_auto(&mut self.span) {
+            Ok(item) => {
+                self.advance();
+                Some(Ok(item))
+            }
+            Err(error) => Some(Err(self.fail(error, offset))),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_finished() {
+            return (0, Some(0));
+        }
+        if self.pending.is_some() {
+            return (1, Some(1));
+        }
+        if let Some(remaining) = self.remaining {
+            return (0, Some(remaining));
+        }
+        let length = self.converter.length();
+        if length != 0 {
+            let count = self.span.len().div_ceil(length);
+            return (count, Some(count));
+        } else {
+            return (1, Some(self.span.len()));
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        for _ in 0..n {
+            if self.is_finished() {
+                return None;
+            }
+            if let Some(error) = self.pending.take() {
+                self.failed = true;
+                return Some(Err(error));
+            }
+            if let Err(error) = self.skip_one() {
+                return Some(Err(error));
+            }
+        }
+        self.next()
+    }
+}
@@ -1,46 +1,212 @@
-use crate::{
-    allocator::Allocator,
-    internal::{error_helper, length},
-};
+use crate::{Error, allocator::Allocator, decode_limits, internal::length};
 
 pub trait Converter<T>: crate::Converter {
-    fn encode(&self, allocator: &mut Allocator, item: &T) -> Result<(), Box<dyn std::error::Error>>;
+    /// # Safety
+    ///
+    /// Return `true` only if encoding a `T` writes exactly its in-memory bytes and every bit pattern of that size is a valid `T`.
+    unsafe fn is_plain_data(&self) -> bool {
+        false
+    }
+
+    fn encode(&self, allocator: &mut Allocator, item: &T) -> Result<(), Error>;
 
-    fn encode_auto(&self, allocator: &mut Allocator, item: &T) -> Result<(), Box<dyn std::error::Error>> {
-        if self.length() != 0 {
+    fn encode_auto(&self, allocator: &mut Allocator, item: &T) -> Result<(), Error> {
+        if is_fixed_length(self) {
             return self.encode(allocator, item);
         } else {
             return self.encode_with_length_prefix(allocator, item);
         }
     }
 
-    fn encode_with_length_prefix(&self, allocator: &mut Allocator, item: &T) -> Result<(), Box<dyn std::error::Error>> {
-        let anchor = allocator.anchor()?;
-        self.encode(allocator, item)?;
-        allocator.finish_anchor(anchor)?;
-        Ok(())
+    fn encode_with_length_prefix(&self, allocator: &mut Allocator, item: &T) -> Result<(), Error> {
+        encode_with_anchor(allocator, |allocator| self.encode(allocator, item))
+    }
+
+    fn encode_to_slice(&self, span: &mut [u8], item: &T) -> Result<usize, Error> {
+        encode_into_slice(span, |allocator| self.encode(allocator, item))
+    }
+
+    fn encoded_length(&self, item: &T) -> Result<usize, Error> {
+        measure(self, |allocator| self.encode(allocator, item))
+    }
+
+    fn encoded_length_auto(&self, item: &T) -> Result<usize, Error> {
+        if is_fixed_length(self) {
+            return Ok(self.length());
+        } else {
+            return self.encoded_length_with_length_prefix(item);
+        }
+    }
+
+    fn encoded_length_with_length_prefix(&self, item: &T) -> Result<usize, Error> {
+        length_with_prefix(self.encoded_length(item)?)
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<T, Error>;
+
+    fn decode_auto(&self, span: &mut &[u8]) -> Result<T, Error> {
+        if is_fixed_length(self) {
+            return decode_fixed(self.length(), span, |body| self.decode(&body));
+        } else {
+            return self.decode_with_length_prefix(span);
+        }
+    }
+
+    fn decode_with_length_prefix(&self, span: &mut &[u8]) -> Result<T, Error> {
+        decode_prefixed(span, |body| self.decode(&body))
+    }
+
+    fn decode_exact(&self, span: &[u8]) -> Result<T, Error> {
+        decode_whole(span, |body| self.decode_auto(body))
+    }
+
+    fn validate(&self, span: &&[u8]) -> Result<(), Error> {
+        self.decode(span).map(|_| ())
+    }
+
+    fn validate_auto(&self, span: &mut &[u8]) -> Result<(), Error> {
+        if is_fixed_length(self) {
+            return decode_fixed(self.length(), span, |body| self.validate(&body));
+        } else {
+            return self.validate_with_length_prefix(span);
+        }
+    }
+
+    fn validate_with_length_prefix(&self, span: &mut &[u8]) -> Result<(), Error> {
+        decode_prefixed(span, |body| self.validate(&body))
+    }
+}
+
+pub trait BorrowConverter<'de, T>: crate::Converter {
+    fn encode(&self, allocator: &mut Allocator, item: &T) -> Result<(), Error>;
+
+    fn encode_auto(&self, allocator: &mut Allocator, item: &T) -> Result<(), Error> {
+        if is_fixed_length(self) {
+            return self.encode(allocator, item);
+        } else {
+            return self.encode_with_length_prefix(allocator, item);
+        }
+    }
+
+    fn encode_with_length_prefix(&self, allocator: &mut Allocator, item: &T) -> Result<(), Error> {
+        encode_with_anchor(allocator, |allocator| self.encode(allocator, item))
+    }
+
+    fn encode_to_slice(&self, span: &mut [u8], item: &T) -> Result<usize, Error> {
+        encode_into_slice(span, |allocator| self.encode(allocator, item))
+    }
+
+    fn encoded_length(&self, item: &T) -> Result<usize, Error> {
+        measure(self, |allocator| self.encode(allocator, item))
     }
 
-    fn decode(&self, span: &&[u8]) -> Result<T, Box<dyn std::error::Error>>;
+    fn encoded_length_auto(&self, item: &T) -> Result<usize, Error> {
+        if is_fixed_length(self) {
+            return Ok(self.length());
+        } else {
+            return self.encoded_length_with_length_prefix(item);
+        }
+    }
+
+    fn encoded_length_with_length_prefix(&self, item: &T) -> Result<usize, Error> {
+        length_with_prefix(self.encoded_length(item)?)
+    }
+
+    fn decode(&self, span: &'de [u8]) -> Result<T, Error>;
 
-    fn decode_auto(&self, span: &mut &[u8]) -> Result<T, Box<dyn std::error::Error>> {
-        if self.length() != 0 {
-            if let Some((head, tail)) = span.split_at_checked(self.length()) {
-                *span = tail;
-                return self.decode(&head);
-            }
-            return Err(error_helper::error_not_enough_bytes());
+    fn decode_auto(&self, span: &mut &'de [u8]) -> Result<T, Error> {
+        if is_fixed_length(self) {
+            return decode_fixed(self.length(), span, |body| self.decode(body));
         } else {
             return self.decode_with_length_prefix(span);
         }
     }
 
-    fn decode_with_length_prefix(&self, span: &mut &[u8]) -> Result<T, Box<dyn std::error::Error>> {
-        self.decode(&decode_with_length_prefix(span)?)
+    fn decode_with_length_prefix(&self, span: &mut &'de [u8]) -> Result<T, Error> {
+        decode_prefixed(span, |body| self.decode(body))
     }
+
+    fn decode_exact(&self, span: &'de [u8]) -> Result<T, Error> {
+        decode_whole(span, |body| self.decode_auto(body))
+    }
+
+    fn validate(&self, span: &'de [u8]) -> Result<(), Error> {
+        self.decode(span).map(|_| ())
+    }
+
+    fn validate_auto(&self, span: &mut &'de [u8]) -> Result<(), Error> {
+        if is_fixed_length(self) {
+            return decode_fixed(self.length(), span, |body| self.validate(body));
+        } else {
+            return self.validate_with_length_prefix(span);
+        }
+    }
+
+    fn validate_with_length_prefix(&self, span: &mut &'de [u8]) -> Result<(), Error> {
+        decode_prefixed(span, |body| self.validate(body))
+    }
+}
+
+fn is_fixed_length<C: crate::Converter + ?Sized>(converter: &C) -> bool {
+    converter.length() != 0 || converter.is_zero_sized()
+}
+
+fn encode_with_anchor(allocator: &mut Allocator, encode: impl FnOnce(&mut Allocator) -> Result<(), Error>) -> Result<(), Error> {
+    let savepoint = allocator.savepoint();
+    let anchor = allocator.anchor()?;
+    let result = encode(allocator).and_then(|_| allocator.finish_anchor(anchor));
+    if result.is_err() {
+        allocator.rollback(savepoint)?;
+    }
+    result
+}
+
+fn encode_into_slice(span: &mut [u8], encode: impl FnOnce(&mut Allocator) -> Result<(), Error>) -> Result<usize, Error> {
+    let mut allocator = unsafe { Allocator::with_external_buffer(span.as_mut_ptr(), span.len()) };
+    encode(&mut allocator)?;
+    Ok(allocator.length())
 }
 
-pub fn encode(allocator: &mut Allocator, number: usize) -> Result<(), Box<dyn std::error::Error>> {
+fn measure<C: crate::Converter + ?Sized>(converter: &C, encode: impl FnOnce(&mut Allocator) -> Result<(), Error>) -> Result<usize, Error> {
+    if is_fixed_length(converter) {
+        return Ok(converter.length());
+    }
+    let mut allocator = Allocator::new();
+    encode(&mut allocator)?;
+    Ok(allocator.length())
+}
+
+fn length_with_prefix(length: usize) -> Result<usize, Error> {
+    length::ensure_length_prefix_length(length)?;
+    Ok(Allocator::finish_anchor_length(length) + length)
+}
+
+fn decode_fixed<'a, R>(length: usize, span: &mut &'a [u8], decode: impl FnOnce(&'a [u8]) -> Result<R, Error>) -> Result<R, Error> {
+    let Some((head, tail)) = span.split_at_checked(length) else {
+        return Err(Error::NotEnoughBytes.with_offset(0));
+    };
+    *span = tail;
+    decode(head).map_err(|error| error.with_offset(0))
+}
+
+fn decode_prefixed<'a, R>(span: &mut &'a [u8], decode: impl FnOnce(&'a [u8]) -> Result<R, Error>) -> Result<R, Error> {
+    let source = *span;
+    let body = decode_with_length_prefix(span).map_err(|error| error.with_offset(0))?;
+    let offset = source.len() - span.len() - body.len();
+    let _guard = decode_limits::enter().map_err(|error| error.with_offset(0))?;
+    decode(body).map_err(|error| error.with_offset(offset))
+}
+
+fn decode_whole<'a, R>(span: &'a [u8], decode: impl FnOnce(&mut &'a [u8]) -> Result<R, Error>) -> Result<R, Error> {
+    let mut body = span;
+    let result = decode(&mut body)?;
+    if body.is_empty() == false {
+        return Err(Error::TrailingBytes.with_offset(span.len() - body.len()));
+    }
+    Ok(result)
+}
+
+pub fn encode(allocator: &mut Allocator, number: usize) -> Result<(), Error> {
     length::ensure_length_prefix_length(number)?;
     let prefix_length = length::encode_length_prefix_length(number);
     let source = allocator.assign(prefix_length)?;
@@ -48,18 +214,18 @@ pub fn encode(allocator: &mut Allocator, number: usize) -> Result<(), Box<dyn st
     Ok(())
 }
 
-pub fn encode_direct(span: &mut [u8], number: usize, bytes_written: &mut usize) -> Result<(), Box<dyn std::error::Error>> {
+pub fn encode_direct(span: &mut [u8], number: usize, bytes_written: &mut usize) -> Result<(), Error> {
     length::ensure_length_prefix_length(number)?;
     let prefix_length = length::encode_length_prefix_length(number);
     if span.len() < prefix_length {
-        return Err(error_helper::error_not_enough_bytes_to_write());
+        return Err(Error::NotEnoughBytesToWrite);
     }
     unsafe { length::encode_length_prefix(span.as_mut_ptr(), number, prefix_length) };
     *bytes_written = prefix_length;
     Ok(())
 }
 
-pub fn decode(span: &mut &[u8]) -> Result<usize, Box<dyn std::error::Error>> {
+pub fn decode(span: &mut &[u8]) -> Result<usize, Error> {
     let source = span.as_ptr();
     let mut offset = 0usize;
     let length = unsafe { length::decode_length_prefix(source, &mut offset, span.len())? };
@@ -69,16 +235,34 @@ pub fn decode(span: &mut &[u8]) -> Result<usize, Box<dyn std::error::Error>> {
     return Ok(length);
 }
 
-pub fn decode_with_length_prefix<'a>(span: &'a mut &[u8]) -> Result<&'a [u8], Box<dyn std::error::Error>> {
+pub fn decode_with_length_prefix<'a>(span: &mut &'a [u8]) -> Result<&'a [u8], Error> {
     let source = span.as_ptr();
     let mut offset = 0usize;
     let length = unsafe { length::decode_length_prefix(source, &mut offset, span.len())? };
     assert!(offset == 1 || offset == 4);
     assert!(offset <= span.len());
     if span.len() < offset + length {
-        return Err(error_helper::error_not_enough_bytes());
+        return Err(Error::NotEnoughBytes);
     }
     let result = unsafe { std::slice::from_raw_parts(source.add(offset), length) };
     *span = unsafe { span.get_unchecked((offset + length)..) };
     Ok(result)
 }
+
+pub fn peek_length(span: &[u8]) -> Result<usize, Error> {
+    let mut offset = 0usize;
+    unsafe { length::decode_length_prefix(span.as_ptr(), &mut offset, span.len()) }
+}
+
+pub fn skip_auto(converter: &dyn crate::Converter, span: &mut &[u8]) -> Result<(), Error> {
+    if is_fixed_length(converter) {
+        *span = span.get(converter.length()..).ok_or(Error::NotEnoughBytes)?;
+        Ok(())
+    } else {
+        skip_with_length_prefix(span)
+    }
+}
+
+pub fn skip_with_length_prefix(span: &mut &[u8]) -> Result<(), Error> {
+    decode_with_length_prefix(span).map(|_| ())
+}
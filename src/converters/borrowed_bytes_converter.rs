@@ -0,0 +1,33 @@
+use crate::{Error, converter::BorrowConverter};
+
+pub struct BorrowedBytesConverter;
+
+impl BorrowedBytesConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl crate::Converter for BorrowedBytesConverter {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<&'static [u8]>()
+    }
+}
+
+impl<'de> BorrowConverter<'de, &'de [u8]> for BorrowedBytesConverter {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &&'de [u8]) -> Result<(), Error> {
+        allocator.append(item)
+    }
+
+    fn encoded_length(&self, item: &&'de [u8]) -> Result<usize, Error> {
+        Ok(item.len())
+    }
+
+    fn decode(&self, span: &'de [u8]) -> Result<&'de [u8], Error> {
+        Ok(span)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{Error, converter::BorrowConverter, decode_limits};
+
+pub struct BorrowedStringConverter;
+
+impl BorrowedStringConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl crate::Converter for BorrowedStringConverter {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<&'static str>()
+    }
+}
+
+impl<'de> BorrowConverter<'de, &'de str> for BorrowedStringConverter {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &&'de str) -> Result<(), Error> {
+        allocator.append(item.as_bytes())
+    }
+
+    fn encoded_length(&self, item: &&'de str) -> Result<usize, Error> {
+        Ok(item.len())
+    }
+
+    fn decode(&self, span: &'de [u8]) -> Result<&'de str, Error> {
+        decode_limits::ensure_string_length(span.len())?;
+        Ok(std::str::from_utf8(span)?)
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{Error, converter::Converter, decode_limits};
+
+pub struct BytesConverter<T: 'static> {
+    _t: std::marker::PhantomData<T>,
+}
+
+impl<T> BytesConverter<T> {
+    pub fn new() -> Self {
+        Self { _t: std::marker::PhantomData }
+    }
+}
+
+impl crate::Converter for BytesConverter<Vec<u8>> {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Vec<u8>>()
+    }
+}
+
+impl Converter<Vec<u8>> for BytesConverter<Vec<u8>> {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &Vec<u8>) -> Result<(), Error> {
+        allocator.append(item)
+    }
+
+    fn encoded_length(&self, item: &Vec<u8>) -> Result<usize, Error> {
+        Ok(item.len())
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<Vec<u8>, Error> {
+        decode_limits::allocate(span.len())?;
+        Ok(span.to_vec())
+    }
+
+    fn validate(&self, _: &&[u8]) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl crate::Converter for BytesConverter<Box<[u8]>> {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Box<[u8]>>()
+    }
+}
+
+impl Converter<Box<[u8]>> for BytesConverter<Box<[u8]>> {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &Box<[u8]>) -> Result<(), Error> {
+        allocator.append(item)
+    }
+
+    fn encoded_length(&self, item: &Box<[u8]>) -> Result<usize, Error> {
+        Ok(item.len())
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<Box<[u8]>, Error> {
+        decode_limits::allocate(span.len())?;
+        Ok(Box::from(*span))
+    }
+
+    fn validate(&self, _: &&[u8]) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> crate::Converter for BytesConverter<[u8; N]> {
+    fn length(&self) -> usize {
+        N
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<[u8; N]>()
+    }
+
+    fn is_zero_sized(&self) -> bool {
+        N == 0
+    }
+}
+
+impl<const N: usize> Converter<[u8; N]> for BytesConverter<[u8; N]> {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &[u8; N]) -> Result<(), Error> {
+        allocator.append(item)
+    }
+
+    fn encoded_length(&self, _: &[u8; N]) -> Result<usize, Error> {
+        Ok(N)
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<[u8; N], Error> {
+        let (head, tail) = span.split_first_chunk::<N>().ok_or(Error::NotEnoughBytes)?;
+        decode_limits::ensure_consumed(tail)?;
+        Ok(*head)
+    }
+}
@@ -1,32 +1,173 @@
-use crate::converter::Converter;
+use crate::{Error, allocator::Allocator, collection_reader, collection_reader::CollectionReader, converter, converter::Converter, decode_limits, internal::length};
+use std::any::{Any, TypeId};
 
 struct CollectionIterator<'a, E> {
-    span: &'a [u8],
-    converter: &'a dyn Converter<E>,
-    first_error: &'a mut Option<Box<dyn std::error::Error>>,
+    reader: CollectionReader<'a, E>,
+    first_error: &'a mut Option<Error>,
+    count: usize,
+    reserved: usize,
+}
+
+impl<'a, E> CollectionIterator<'a, E> {
+    fn ensure_limits(&self) -> Result<(), Error> {
+        decode_limits::ensure_collection_length(self.count)?;
+        if self.count > self.reserved {
+            decode_limits::allocate(std::mem::size_of::<E>())?;
+        }
+        Ok(())
+    }
 }
 
 impl<'a, E> Iterator for CollectionIterator<'a, E> {
     type Item = E;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.span.is_empty() || self.first_error.is_some() {
-            None
-        } else {
-            match self.converter.decode_auto(&mut self.span) {
-                Ok(item) => Some(item),
-                Err(error) => {
-                    *self.first_error = Some(error);
-                    None
+        let index = self.reader.index();
+        match self.reader.next() {
+            Some(Ok(item)) => {
+                self.count += 1;
+                if let Err(error) = self.ensure_limits() {
+                    *self.first_error = Some(error.with_index(index));
+                    return None;
                 }
+                Some(item)
             }
+            Some(Err(error)) => {
+                *self.first_error = Some(error);
+                None
+            }
+            None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.reader.size_hint()
+    }
+}
+
+pub(crate) fn encode_collection<'a, I: IntoIterator<Item = &'a E> + Clone, E: 'a>(converter: &dyn Converter<E>, allocator: &mut Allocator, items: I) -> Result<(), Error> {
+    let savepoint = allocator.savepoint();
+    if converter.is_zero_sized() {
+        converter::encode(allocator, items.clone().into_iter().count())?;
+    }
+    for i in items {
+        if let Err(error) = converter.encode_auto(allocator, i) {
+            allocator.rollback(savepoint)?;
+            return Err(error);
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn encoded_collection_length<'a, I: IntoIterator<Item = &'a E> + Clone, E: 'a>(converter: &dyn Converter<E>, items: I) -> Result<usize, Error> {
+    if converter.is_zero_sized() {
+        let count = items.into_iter().count();
+        length::ensure_length_prefix_length(count)?;
+        return Ok(length::encode_length_prefix_length(count));
+    }
+    let mut result = 0usize;
+    for i in items {
+        result = result.checked_add(converter.encoded_length_auto(i)?).ok_or(Error::MaxCapacityOverflow)?;
+    }
+    Ok(result)
+}
+
+fn ensure_element_remainder(length: usize, span: &[u8]) -> Result<(), Error> {
+    if length != 0 && span.len().is_multiple_of(length) == false {
+        let count = span.len() / length;
+        return Err(Error::NotEnoughBytes.with_index(count).with_offset(count * length));
+    }
+    Ok(())
+}
+
+fn decode_elements<E, R>(converter: &dyn Converter<E>, span: &[u8], collect: impl FnOnce(&mut CollectionIterator<'_, E>) -> Result<R, Error>) -> Result<R, Error> {
+    ensure_element_remainder(converter.length(), span)?;
+    let reader = CollectionReader::new(converter, span);
+    let reserved = reader.size_hint().0;
+    decode_limits::ensure_collection_length(reserved)?;
+    decode_limits::allocate(reserved.saturating_mul(std::mem::size_of::<E>()))?;
+    let mut first_error = None;
+    let mut iterator = CollectionIterator {
+        reader: reader,
+        first_error: &mut first_error,
+        count: 0,
+        reserved: reserved,
+    };
+    let result = collect(&mut iterator)?;
+    let offset = span.len() - iterator.reader.remaining().len();
+    if let Some(error) = first_error {
+        return Err(error);
+    }
+    decode_limits::ensure_consumed(&span[offset..]).map_err(|error| error.with_offset(offset))?;
+    Ok(result)
+}
+
+pub(crate) fn decode_collection<T: FromIterator<E>, E>(converter: &dyn Converter<E>, span: &[u8]) -> Result<(T, usize), Error> {
+    decode_elements(converter, span, |iterator| {
+        let result: T = iterator.by_ref().collect();
+        Ok((result, iterator.count))
+    })
+}
+
+fn decode_unique_collection<T: UniqueCollection>(converter: &dyn Converter<<T as IntoIterator>::Item>, span: &[u8]) -> Result<T, Error> {
+    decode_elements(converter, span, |iterator| {
+        let mut result = T::default();
+        loop {
+            let offset = span.len() - iterator.reader.remaining().len();
+            let Some(item) = iterator.next() else {
+                break;
+            };
+            if result.insert_unique(item) == false {
+                return Err(Error::DuplicateElement.with_index(iterator.count - 1).with_offset(offset));
+            }
+        }
+        Ok(result)
+    })
+}
+
+pub(crate) fn validate_collection<E>(converter: &dyn Converter<E>, span: &[u8]) -> Result<(), Error> {
+    ensure_element_remainder(converter.length(), span)?;
+    let mut body = span;
+    if converter.is_zero_sized() {
+        let count = collection_reader::decode_zero_sized_count(&mut body).map_err(|error| error.with_offset(0))?;
+        if count != 0 {
+            let offset = span.len() - body.len();
+            converter.validate(&&body[..0]).map_err(|error| error.with_index(0).with_offset(offset))?;
+        }
+        return decode_limits::ensure_consumed(body).map_err(|error| error.with_offset(span.len() - body.len()));
+    }
+    let mut index = 0usize;
+    while body.is_empty() == false {
+        let offset = span.len() - body.len();
+        converter.validate_auto(&mut body).map_err(|error| error.with_index(index).with_offset(offset))?;
+        index += 1;
+        decode_limits::ensure_collection_length(index).map_err(|error| error.with_index(index - 1))?;
+    }
+    Ok(())
+}
+
+pub trait UniqueCollection: IntoIterator + Default {
+    fn insert_unique(&mut self, item: Self::Item) -> bool;
+}
+
+impl<E: Eq + std::hash::Hash, S: std::hash::BuildHasher + Default> UniqueCollection for std::collections::HashSet<E, S> {
+    fn insert_unique(&mut self, item: Self::Item) -> bool {
+        self.insert(item)
+    }
+}
+
+impl<E: Ord> UniqueCollection for std::collections::BTreeSet<E> {
+    fn insert_unique(&mut self, item: Self::Item) -> bool {
+        self.insert(item)
+    }
 }
 
+type UniqueDecoder<T> = fn(&dyn Converter<<T as IntoIterator>::Item>, &[u8]) -> Result<T, Error>;
+
 pub struct CollectionConverter<T: IntoIterator + 'static> {
     _t: std::marker::PhantomData<T>,
     converter: Box<dyn Converter<<T as IntoIterator>::Item>>,
+    unique: Option<UniqueDecoder<T>>,
 }
 
 impl<T: IntoIterator> CollectionConverter<T> {
@@ -34,10 +175,63 @@ impl<T: IntoIterator> CollectionConverter<T> {
         Self {
             _t: std::marker::PhantomData,
             converter: converter,
+            unique: None,
         }
     }
 }
 
+impl<T: UniqueCollection> CollectionConverter<T> {
+    pub fn with_unique_elements(converter: Box<dyn Converter<<T as IntoIterator>::Item>>) -> Self {
+        Self {
+            _t: std::marker::PhantomData,
+            converter: converter,
+            unique: Some(decode_unique_collection::<T>),
+        }
+    }
+}
+
+impl<T: IntoIterator> CollectionConverter<T> {
+    fn is_plain_data(&self) -> bool {
+        let length = self.converter.length();
+        length != 0 && length == std::mem::size_of::<<T as IntoIterator>::Item>() && unsafe { self.converter.is_plain_data() }
+    }
+
+    fn as_plain_data_slice<'a>(&self, item: &'a T) -> Option<&'a [<T as IntoIterator>::Item]> {
+        if self.is_plain_data() == false {
+            return None;
+        }
+        let item = item as &dyn Any;
+        if let Some(items) = item.downcast_ref::<Vec<<T as IntoIterator>::Item>>() {
+            return Some(items);
+        }
+        if let Some(items) = item.downcast_ref::<Box<[<T as IntoIterator>::Item]>>() {
+            return Some(items);
+        }
+        None
+    }
+
+    fn decode_plain_data(&self, span: &[u8]) -> Option<Result<T, Error>> {
+        let is_vec = TypeId::of::<T>() == TypeId::of::<Vec<<T as IntoIterator>::Item>>();
+        let is_box = TypeId::of::<T>() == TypeId::of::<Box<[<T as IntoIterator>::Item]>>();
+        if (is_vec || is_box) == false || self.is_plain_data() == false {
+            return None;
+        }
+        let count = span.len() / self.converter.length();
+        let checked = ensure_element_remainder(self.converter.length(), span);
+        if let Err(error) = checked.and_then(|_| decode_limits::ensure_collection_length(count)).and_then(|_| decode_limits::allocate(span.len())) {
+            return Some(Err(error));
+        }
+        assert!(count * self.converter.length() == span.len());
+        let mut items = Vec::<<T as IntoIterator>::Item>::with_capacity(count);
+        unsafe {
+            std::ptr::copy_nonoverlapping(span.as_ptr(), items.as_mut_ptr().cast::<u8>(), span.len());
+            items.set_len(count);
+        }
+        let result: Box<dyn Any> = if is_vec { Box::new(items) } else { Box::new(items.into_boxed_slice()) };
+        Some(Ok(*result.downcast::<T>().unwrap()))
+    }
+}
+
 impl<T: IntoIterator> crate::Converter for CollectionConverter<T> {
     fn length(&self) -> usize {
         0
@@ -52,21 +246,38 @@ impl<T: IntoIterator + FromIterator<<T as IntoIterator>::Item>> Converter<T> for
 where
     for<'a> &'a T: IntoIterator<Item = &'a <T as IntoIterator>::Item>,
 {
-    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &T) -> Result<(), Box<dyn std::error::Error>> {
-        for i in item {
-            self.converter.encode_auto(allocator, &i)?;
+    fn encode(&self, allocator: &mut Allocator, item: &T) -> Result<(), Error> {
+        if let Some(items) = self.as_plain_data_slice(item) {
+            return allocator.append(unsafe { std::slice::from_raw_parts(items.as_ptr().cast::<u8>(), std::mem::size_of_val(items)) });
         }
-        Ok(())
+        encode_collection(&*self.converter, allocator, item)
     }
 
-    fn decode(&self, span: &&[u8]) -> Result<T, Box<dyn std::error::Error>> {
-        let mut first_error = None;
-        let iterator = CollectionIterator {
-            span: span,
-            converter: &*self.converter,
-            first_error: &mut first_error,
-        };
-        let result: T = iterator.collect();
-        if let Some(error) = first_error { Err(error) } else { Ok(result) }
+    fn encoded_length(&self, item: &T) -> Result<usize, Error> {
+        if let Some(items) = self.as_plain_data_slice(item) {
+            return Ok(std::mem::size_of_val(items));
+        }
+        encoded_collection_length(&*self.converter, item)
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<T, Error> {
+        if let Some(unique) = self.unique {
+            return unique(&*self.converter, span);
+        }
+        if let Some(result) = self.decode_plain_data(span) {
+            return result;
+        }
+        Ok(decode_collection::<T, _>(&*self.converter, span)?.0)
+    }
+
+    fn validate(&self, span: &&[u8]) -> Result<(), Error> {
+        if self.unique.is_some() {
+            return self.decode(span).map(|_| ());
+        }
+        if self.is_plain_data() {
+            ensure_element_remainder(self.converter.length(), span)?;
+            return decode_limits::ensure_collection_length(span.len() / self.converter.length());
+        }
+        validate_collection(&*self.converter, span)
     }
 }
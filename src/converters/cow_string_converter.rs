@@ -0,0 +1,35 @@
+use crate::{Error, converter::BorrowConverter, decode_limits};
+use std::borrow::Cow;
+
+pub struct CowStringConverter;
+
+impl CowStringConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl crate::Converter for CowStringConverter {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Cow<'static, str>>()
+    }
+}
+
+impl<'de> BorrowConverter<'de, Cow<'de, str>> for CowStringConverter {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &Cow<'de, str>) -> Result<(), Error> {
+        allocator.append(item.as_bytes())
+    }
+
+    fn encoded_length(&self, item: &Cow<'de, str>) -> Result<usize, Error> {
+        Ok(item.len())
+    }
+
+    fn decode(&self, span: &'de [u8]) -> Result<Cow<'de, str>, Error> {
+        decode_limits::ensure_string_length(span.len())?;
+        Ok(Cow::Borrowed(std::str::from_utf8(span)?))
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{Error, converter::Converter, decode_limits, internal::endian};
+use std::time::Duration;
+
+pub struct DurationConverter;
+
+impl DurationConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl crate::Converter for DurationConverter {
+    fn length(&self) -> usize {
+        12
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Duration>()
+    }
+}
+
+impl Converter<Duration> for DurationConverter {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &Duration) -> Result<(), Error> {
+        let target = allocator.assign(12)?;
+        unsafe { endian::encode_le_unaligned(target, &item.as_secs()) };
+        unsafe { endian::encode_le_unaligned(target.add(8), &item.subsec_nanos()) };
+        Ok(())
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<Duration, Error> {
+        if span.len() < 12 {
+            return Err(Error::NotEnoughBytes);
+        }
+        decode_limits::ensure_consumed(&span[12..])?;
+        let secs = unsafe { endian::decode_le_unaligned::<u64>(span.as_ptr()) };
+        let nanos = unsafe { endian::decode_le_unaligned::<u32>(span.as_ptr().add(8)) };
+        if nanos >= 1_000_000_000 {
+            return Err(Error::InvalidValue("nanoseconds out of range."));
+        }
+        Ok(Duration::new(secs, nanos))
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{Error, converter::Converter, decode_limits};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+pub struct Ipv4AddrConverter;
+
+impl Ipv4AddrConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl crate::Converter for Ipv4AddrConverter {
+    fn length(&self) -> usize {
+        4
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Ipv4Addr>()
+    }
+}
+
+impl Converter<Ipv4Addr> for Ipv4AddrConverter {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &Ipv4Addr) -> Result<(), Error> {
+        allocator.append(&item.octets())
+    }
+
+    fn encoded_length(&self, _: &Ipv4Addr) -> Result<usize, Error> {
+        Ok(4)
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<Ipv4Addr, Error> {
+        let (head, tail) = span.split_first_chunk::<4>().ok_or(Error::NotEnoughBytes)?;
+        decode_limits::ensure_consumed(tail)?;
+        Ok(Ipv4Addr::from(*head))
+    }
+}
+
+pub struct Ipv6AddrConverter;
+
+impl Ipv6AddrConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl crate::Converter for Ipv6AddrConverter {
+    fn length(&self) -> usize {
+        16
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Ipv6Addr>()
+    }
+}
+
+impl Converter<Ipv6Addr> for Ipv6AddrConverter {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &Ipv6Addr) -> Result<(), Error> {
+        allocator.append(&item.octets())
+    }
+
+    fn encoded_length(&self, _: &Ipv6Addr) -> Result<usize, Error> {
+        Ok(16)
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<Ipv6Addr, Error> {
+        let (head, tail) = span.split_first_chunk::<16>().ok_or(Error::NotEnoughBytes)?;
+        decode_limits::ensure_consumed(tail)?;
+        Ok(Ipv6Addr::from(*head))
+    }
+}
+
+pub struct IpAddrConverter;
+
+impl IpAddrConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl crate::Converter for IpAddrConverter {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<IpAddr>()
+    }
+}
+
+impl Converter<IpAddr> for IpAddrConverter {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &IpAddr) -> Result<(), Error> {
+        match item {
+            IpAddr::V4(address) => allocator.append(&address.octets()),
+            IpAddr::V6(address) => allocator.append(&address.octets()),
+        }
+    }
+
+    fn encoded_length(&self, item: &IpAddr) -> Result<usize, Error> {
+        match item {
+            IpAddr::V4(_) => Ok(4),
+            IpAddr::V6(_) => Ok(16),
+        }
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<IpAddr, Error> {
+        match span.len() {
+            4 => Ok(IpAddr::V4(Ipv4AddrConverter::new().decode(span)?)),
+            16 => Ok(IpAddr::V6(Ipv6AddrConverter::new().decode(span)?)),
+            _ => Err(Error::InvalidValue("invalid ip address length.")),
+        }
+    }
+}
@@ -1,7 +1,4 @@
-use crate::{
-    converter::Converter,
-    internal::{endian, error_helper},
-};
+use crate::{Error, converter::Converter, decode_limits, internal::endian};
 
 pub struct LittleEndianConverter<T: 'static> {
     _t: std::marker::PhantomData<T>,
@@ -9,6 +6,7 @@ pub struct LittleEndianConverter<T: 'static> {
 
 impl<T> LittleEndianConverter<T> {
     pub fn new() -> Self {
+        const { assert!(std::mem::size_of::<T>() != 0, "use ZeroSizedConverter for zero-sized types.") };
         Self { _t: std::marker::PhantomData }
     }
 }
@@ -24,15 +22,27 @@ impl<T> crate::Converter for LittleEndianConverter<T> {
 }
 
 impl<T> Converter<T> for LittleEndianConverter<T> {
-    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &T) -> Result<(), Box<dyn std::error::Error>> {
+    unsafe fn is_plain_data(&self) -> bool {
+        cfg!(target_endian = "little")
+    }
+
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &T) -> Result<(), Error> {
         unsafe { endian::encode_le_unaligned::<T>(allocator.assign(std::mem::size_of::<T>())?, item) };
         Ok(())
     }
 
-    fn decode(&self, span: &&[u8]) -> Result<T, Box<dyn std::error::Error>> {
+    fn decode(&self, span: &&[u8]) -> Result<T, Error> {
         if span.len() < std::mem::size_of::<T>() {
-            return Err(error_helper::error_not_enough_bytes());
+            return Err(Error::NotEnoughBytes);
         }
+        decode_limits::ensure_consumed(&span[std::mem::size_of::<T>()..])?;
         Ok(unsafe { endian::decode_le_unaligned::<T>(span.as_ptr()) })
     }
+
+    fn validate(&self, span: &&[u8]) -> Result<(), Error> {
+        if span.len() < std::mem::size_of::<T>() {
+            return Err(Error::NotEnoughBytes);
+        }
+        decode_limits::ensure_consumed(&span[std::mem::size_of::<T>()..])
+    }
 }
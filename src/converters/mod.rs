@@ -1,3 +1,16 @@
+pub mod borrowed_bytes_converter;
+pub mod borrowed_string_converter;
+pub mod bytes_converter;
 pub mod collection_converter;
+pub mod cow_string_converter;
+pub mod duration_converter;
+pub mod ip_address_converter;
 pub mod little_endian_converter;
+pub mod range_converter;
+pub mod result_converter;
+pub mod slice_converter;
+pub mod socket_address_converter;
 pub mod string_converter;
+pub mod system_time_converter;
+pub mod wrapper_converter;
+pub mod zero_sized_converter;
@@ -0,0 +1,112 @@
+use crate::{Error, converter::Converter, decode_limits};
+use std::ops::{Range, RangeInclusive};
+
+pub struct RangeConverter<T: 'static> {
+    converter: Box<dyn Converter<T>>,
+}
+
+impl<T> RangeConverter<T> {
+    pub fn new(converter: Box<dyn Converter<T>>) -> Self {
+        Self { converter: converter }
+    }
+}
+
+impl<T> crate::Converter for RangeConverter<T> {
+    fn length(&self) -> usize {
+        self.converter.length() * 2
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Range<T>>()
+    }
+
+    fn is_zero_sized(&self) -> bool {
+        self.converter.is_zero_sized()
+    }
+}
+
+impl<T> Converter<Range<T>> for RangeConverter<T> {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &Range<T>) -> Result<(), Error> {
+        self.converter.encode_auto(allocator, &item.start)?;
+        self.converter.encode_auto(allocator, &item.end)?;
+        Ok(())
+    }
+
+    fn encoded_length(&self, item: &Range<T>) -> Result<usize, Error> {
+        let start = self.converter.encoded_length_auto(&item.start)?;
+        let end = self.converter.encoded_length_auto(&item.end)?;
+        start.checked_add(end).ok_or(Error::MaxCapacityOverflow)
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<Range<T>, Error> {
+        let mut body = *span;
+        let start = self.converter.decode_auto(&mut body).map_err(|error| error.with_field("start"))?;
+        let offset = span.len() - body.len();
+        let end = self.converter.decode_auto(&mut body).map_err(|error| error.with_field("end").with_offset(offset))?;
+        decode_limits::ensure_consumed(body).map_err(|error| error.with_offset(span.len() - body.len()))?;
+        Ok(start..end)
+    }
+
+    fn validate(&self, span: &&[u8]) -> Result<(), Error> {
+        let mut body = *span;
+        self.converter.validate_auto(&mut body).map_err(|error| error.with_field("start"))?;
+        let offset = span.len() - body.len();
+        self.converter.validate_auto(&mut body).map_err(|error| error.with_field("end").with_offset(offset))?;
+        decode_limits::ensure_consumed(body).map_err(|error| error.with_offset(span.len() - body.len()))
+    }
+}
+
+pub struct RangeInclusiveConverter<T: 'static> {
+    converter: Box<dyn Converter<T>>,
+}
+
+impl<T> RangeInclusiveConverter<T> {
+    pub fn new(converter: Box<dyn Converter<T>>) -> Self {
+        Self { converter: converter }
+    }
+}
+
+impl<T> crate::Converter for RangeInclusiveConverter<T> {
+    fn length(&self) -> usize {
+        self.converter.length() * 2
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<RangeInclusive<T>>()
+    }
+
+    fn is_zero_sized(&self) -> bool {
+        self.converter.is_zero_sized()
+    }
+}
+
+impl<T> Converter<RangeInclusive<T>> for RangeInclusiveConverter<T> {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &RangeInclusive<T>) -> Result<(), Error> {
+        self.converter.encode_auto(allocator, item.start())?;
+        self.converter.encode_auto(allocator, item.end())?;
+        Ok(())
+    }
+
+    fn encoded_length(&self, item: &RangeInclusive<T>) -> Result<usize, Error> {
+        let start = self.converter.encoded_length_auto(item.start())?;
+        let end = self.converter.encoded_length_auto(item.end())?;
+        start.checked_add(end).ok_or(Error::MaxCapacityOverflow)
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<RangeInclusive<T>, Error> {
+        let mut body = *span;
+        let start = self.converter.decode_auto(&mut body).map_err(|error| error.with_field("start"))?;
+        let offset = span.len() - body.len();
+        let end = self.converter.decode_auto(&mut body).map_err(|error| error.with_field("end").with_offset(offset))?;
+        decode_limits::ensure_consumed(body).map_err(|error| error.with_offset(span.len() - body.len()))?;
+        Ok(start..=end)
+    }
+
+    fn validate(&self, span: &&[u8]) -> Result<(), Error> {
+        let mut body = *span;
+        self.converter.validate_auto(&mut body).map_err(|error| error.with_field("start"))?;
+        let offset = span.len() - body.len();
+        self.converter.validate_auto(&mut body).map_err(|error| error.with_field("end").with_offset(offset))?;
+        decode_limits::ensure_consumed(body).map_err(|error| error.with_offset(span.len() - body.len()))
+    }
+}
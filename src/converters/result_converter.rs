@@ -0,0 +1,77 @@
+use crate::{Error, converter::Converter, decode_limits};
+
+pub struct ResultConverter<T: 'static, E: 'static> {
+    ok_converter: Box<dyn Converter<T>>,
+    err_converter: Box<dyn Converter<E>>,
+}
+
+impl<T, E> ResultConverter<T, E> {
+    const OK_TAG: u8 = 0;
+    const ERR_TAG: u8 = 1;
+
+    pub fn new(ok_converter: Box<dyn Converter<T>>, err_converter: Box<dyn Converter<E>>) -> Self {
+        Self {
+            ok_converter: ok_converter,
+            err_converter: err_converter,
+        }
+    }
+}
+
+impl<T, E> crate::Converter for ResultConverter<T, E> {
+    fn length(&self) -> usize {
+        let length = self.ok_converter.length();
+        if length != 0 && length == self.err_converter.length() { 1 + length } else { 0 }
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Result<T, E>>()
+    }
+}
+
+impl<T, E> Converter<Result<T, E>> for ResultConverter<T, E> {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &Result<T, E>) -> Result<(), Error> {
+        match item {
+            Ok(value) => {
+                allocator.append(&[Self::OK_TAG])?;
+                self.ok_converter.encode_auto(allocator, value)
+            }
+            Err(value) => {
+                allocator.append(&[Self::ERR_TAG])?;
+                self.err_converter.encode_auto(allocator, value)
+            }
+        }
+    }
+
+    fn encoded_length(&self, item: &Result<T, E>) -> Result<usize, Error> {
+        let length = match item {
+            Ok(value) => self.ok_converter.encoded_length_auto(value)?,
+            Err(value) => self.err_converter.encoded_length_auto(value)?,
+        };
+        length.checked_add(1).ok_or(Error::MaxCapacityOverflow)
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<Result<T, E>, Error> {
+        let Some((tag, mut body)) = span.split_first() else {
+            return Err(Error::NotEnoughBytes);
+        };
+        let result = match *tag {
+            Self::OK_TAG => Ok(self.ok_converter.decode_auto(&mut body).map_err(|error| error.with_field("ok").with_offset(1))?),
+            Self::ERR_TAG => Err(self.err_converter.decode_auto(&mut body).map_err(|error| error.with_field("err").with_offset(1))?),
+            _ => return Err(Error::InvalidValue("invalid result tag.")),
+        };
+        decode_limits::ensure_consumed(body).map_err(|error| error.with_offset(span.len() - body.len()))?;
+        Ok(result)
+    }
+
+    fn validate(&self, span: &&[u8]) -> Result<(), Error> {
+        let Some((tag, mut body)) = span.split_first() else {
+            return Err(Error::NotEnoughBytes);
+        };
+        match *tag {
+            Self::OK_TAG => self.ok_converter.validate_auto(&mut body).map_err(|error| error.with_field("ok").with_offset(1))?,
+            Self::ERR_TAG => self.err_converter.validate_auto(&mut body).map_err(|error| error.with_field("err").with_offset(1))?,
+            _ => return Err(Error::InvalidValue("invalid result tag.")),
+        }
+        decode_limits::ensure_consumed(body).map_err(|error| error.with_offset(span.len() - body.len()))
+    }
+}
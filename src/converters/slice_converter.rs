@@ -0,0 +1,43 @@
+use crate::{Error, converter::Converter, converters::collection_converter};
+
+pub struct SliceConverter<T: 'static, E: 'static> {
+    _t: std::marker::PhantomData<T>,
+    converter: Box<dyn Converter<E>>,
+}
+
+impl<T, E> SliceConverter<T, E> {
+    pub fn new(converter: Box<dyn Converter<E>>) -> Self {
+        Self {
+            _t: std::marker::PhantomData,
+            converter: converter,
+        }
+    }
+}
+
+impl<T, E> crate::Converter for SliceConverter<T, E> {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<T>()
+    }
+}
+
+impl<T: std::ops::Deref<Target = [E]> + FromIterator<E>, E> Converter<T> for SliceConverter<T, E> {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &T) -> Result<(), Error> {
+        collection_converter::encode_collection(&*self.converter, allocator, item.iter())
+    }
+
+    fn encoded_length(&self, item: &T) -> Result<usize, Error> {
+        collection_converter::encoded_collection_length(&*self.converter, item.iter())
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<T, Error> {
+        Ok(collection_converter::decode_collection::<T, E>(&*self.converter, span)?.0)
+    }
+
+    fn validate(&self, span: &&[u8]) -> Result<(), Error> {
+        collection_converter::validate_collection(&*self.converter, span)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    Error,
+    converter::Converter,
+    converters::ip_address_converter::{Ipv4AddrConverter, Ipv6AddrConverter},
+    decode_limits,
+    internal::endian,
+};
+use std::net::{IpAddr, SocketAddr, SocketAddrV6};
+
+pub struct SocketAddrConverter;
+
+impl SocketAddrConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl crate::Converter for SocketAddrConverter {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<SocketAddr>()
+    }
+}
+
+impl Converter<SocketAddr> for SocketAddrConverter {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &SocketAddr) -> Result<(), Error> {
+        match item.ip() {
+            IpAddr::V4(address) => Ipv4AddrConverter::new().encode(allocator, &address)?,
+            IpAddr::V6(address) => Ipv6AddrConverter::new().encode(allocator, &address)?,
+        }
+        unsafe { endian::encode_le_unaligned(allocator.assign(2)?, &item.port()) };
+        if let SocketAddr::V6(address) = item
+            && (address.flowinfo() != 0 || address.scope_id() != 0)
+        {
+            unsafe { endian::encode_le_unaligned(allocator.assign(4)?, &address.flowinfo()) };
+            unsafe { endian::encode_le_unaligned(allocator.assign(4)?, &address.scope_id()) };
+        }
+        Ok(())
+    }
+
+    fn encoded_length(&self, item: &SocketAddr) -> Result<usize, Error> {
+        match item {
+            SocketAddr::V4(_) => Ok(6),
+            SocketAddr::V6(address) if address.flowinfo() != 0 || address.scope_id() != 0 => Ok(26),
+            SocketAddr::V6(_) => Ok(18),
+        }
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<SocketAddr, Error> {
+        if span.len() == 26 {
+            let address = Ipv6AddrConverter::new().decode(&&span[..16])?;
+            let port = unsafe { endian::decode_le_unaligned::<u16>(span.as_ptr().add(16)) };
+            let flowinfo = unsafe { endian::decode_le_unaligned::<u32>(span.as_ptr().add(18)) };
+            let scope_id = unsafe { endian::decode_le_unaligned::<u32>(span.as_ptr().add(22)) };
+            if flowinfo == 0 && scope_id == 0 && decode_limits::is_strict() {
+                return Err(Error::InvalidValue("non-canonical socket address."));
+            }
+            return Ok(SocketAddr::V6(SocketAddrV6::new(address, port, flowinfo, scope_id)));
+        }
+        let address = match span.len() {
+            6 => IpAddr::V4(Ipv4AddrConverter::new().decode(&&span[..4])?),
+            18 => IpAddr::V6(Ipv6AddrConverter::new().decode(&&span[..16])?),
+            _ => return Err(Error::InvalidValue("invalid socket address length.")),
+        };
+        let port = unsafe { endian::decode_le_unaligned::<u16>(span.as_ptr().add(span.len() - 2)) };
+        Ok(SocketAddr::new(address, port))
+    }
+}
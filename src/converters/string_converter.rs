@@ -1,4 +1,4 @@
-use crate::converter::Converter;
+use crate::{Error, converter::Converter, decode_limits};
 
 pub struct StringConverter;
 
@@ -19,14 +19,26 @@ impl crate::Converter for StringConverter {
 }
 
 impl Converter<std::string::String> for StringConverter {
-    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &std::string::String) -> Result<(), Box<dyn std::error::Error>> {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &std::string::String) -> Result<(), Error> {
         if !item.is_empty() {
             unsafe { std::ptr::copy_nonoverlapping(item.as_ptr(), allocator.assign(item.len())?, item.len()) };
         }
         Ok(())
     }
 
-    fn decode(&self, span: &&[u8]) -> Result<std::string::String, Box<dyn std::error::Error>> {
+    fn encoded_length(&self, item: &std::string::String) -> Result<usize, Error> {
+        Ok(item.len())
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<std::string::String, Error> {
+        decode_limits::ensure_string_length(span.len())?;
+        decode_limits::allocate(span.len())?;
         Ok(std::str::from_utf8(&span)?.to_string())
     }
+
+    fn validate(&self, span: &&[u8]) -> Result<(), Error> {
+        decode_limits::ensure_string_length(span.len())?;
+        std::str::from_utf8(span)?;
+        Ok(())
+    }
 }
@@ -0,0 +1,55 @@
+use crate::{Error, converter::Converter, decode_limits, internal::endian};
+use std::time::{Duration, SystemTime};
+
+pub struct SystemTimeConverter;
+
+impl SystemTimeConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl crate::Converter for SystemTimeConverter {
+    fn length(&self) -> usize {
+        12
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<SystemTime>()
+    }
+}
+
+impl Converter<SystemTime> for SystemTimeConverter {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &SystemTime) -> Result<(), Error> {
+        let (secs, nanos) = match item.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => (i64::try_from(duration.as_secs()).map_err(|_| Error::InvalidValue("system time out of range."))?, duration.subsec_nanos()),
+            Err(error) => {
+                let duration = error.duration();
+                let secs = i64::try_from(duration.as_secs()).map_err(|_| Error::InvalidValue("system time out of range."))?;
+                if duration.subsec_nanos() == 0 { (-secs, 0) } else { (-secs - 1, 1_000_000_000 - duration.subsec_nanos()) }
+            }
+        };
+        let target = allocator.assign(12)?;
+        unsafe { endian::encode_le_unaligned(target, &secs) };
+        unsafe { endian::encode_le_unaligned(target.add(8), &nanos) };
+        Ok(())
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<SystemTime, Error> {
+        if span.len() < 12 {
+            return Err(Error::NotEnoughBytes);
+        }
+        decode_limits::ensure_consumed(&span[12..])?;
+        let secs = unsafe { endian::decode_le_unaligned::<i64>(span.as_ptr()) };
+        let nanos = unsafe { endian::decode_le_unaligned::<u32>(span.as_ptr().add(8)) };
+        if nanos >= 1_000_000_000 {
+            return Err(Error::InvalidValue("nanoseconds out of range."));
+        }
+        let result = if secs >= 0 {
+            SystemTime::UNIX_EPOCH.checked_add(Duration::new(secs as u64, nanos))
+        } else {
+            SystemTime::UNIX_EPOCH.checked_sub(Duration::from_secs(secs.unsigned_abs())).and_then(|x| x.checked_add(Duration::from_nanos(nanos as u64)))
+        };
+        result.ok_or(Error::InvalidValue("system time out of range."))
+    }
+}
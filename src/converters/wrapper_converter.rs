@@ -0,0 +1,170 @@
+use crate::{Error, converter::Converter};
+use std::{borrow::Cow, cmp::Reverse, num::Wrapping, rc::Rc, sync::Arc};
+
+pub trait Wrapper: Sized {
+    type Inner;
+
+    fn generic_argument() -> std::any::TypeId;
+
+    fn inner(&self) -> &Self::Inner;
+
+    fn wrap(inner: Self::Inner) -> Result<Self, Error>;
+}
+
+impl<T: 'static> Wrapper for Box<T> {
+    type Inner = T;
+
+    fn generic_argument() -> std::any::TypeId {
+        std::any::TypeId::of::<Self>()
+    }
+
+    fn inner(&self) -> &T {
+        self
+    }
+
+    fn wrap(inner: T) -> Result<Self, Error> {
+        Ok(Box::new(inner))
+    }
+}
+
+impl<T: 'static> Wrapper for Rc<T> {
+    type Inner = T;
+
+    fn generic_argument() -> std::any::TypeId {
+        std::any::TypeId::of::<Self>()
+    }
+
+    fn inner(&self) -> &T {
+        self
+    }
+
+    fn wrap(inner: T) -> Result<Self, Error> {
+        Ok(Rc::new(inner))
+    }
+}
+
+impl<T: 'static> Wrapper for Arc<T> {
+    type Inner = T;
+
+    fn generic_argument() -> std::any::TypeId {
+        std::any::TypeId::of::<Self>()
+    }
+
+    fn inner(&self) -> &T {
+        self
+    }
+
+    fn wrap(inner: T) -> Result<Self, Error> {
+        Ok(Arc::new(inner))
+    }
+}
+
+impl<'a, T: Clone + 'static> Wrapper for Cow<'a, T> {
+    type Inner = T;
+
+    fn generic_argument() -> std::any::TypeId {
+        std::any::TypeId::of::<Cow<'static, T>>()
+    }
+
+    fn inner(&self) -> &T {
+        self
+    }
+
+    fn wrap(inner: T) -> Result<Self, Error> {
+        Ok(Cow::Owned(inner))
+    }
+}
+
+impl<T: 'static> Wrapper for Wrapping<T> {
+    type Inner = T;
+
+    fn generic_argument() -> std::any::TypeId {
+        std::any::TypeId::of::<Self>()
+    }
+
+    fn inner(&self) -> &T {
+        &self.0
+    }
+
+    fn wrap(inner: T) -> Result<Self, Error> {
+        Ok(Wrapping(inner))
+    }
+}
+
+impl<T: 'static> Wrapper for Reverse<T> {
+    type Inner = T;
+
+    fn generic_argument() -> std::any::TypeId {
+        std::any::TypeId::of::<Self>()
+    }
+
+    fn inner(&self) -> &T {
+        &self.0
+    }
+
+    fn wrap(inner: T) -> Result<Self, Error> {
+        Ok(Reverse(inner))
+    }
+}
+
+macro_rules! impl_non_zero_wrapper {
+    ($($t:ty),*) => {
+        $(
+            impl Wrapper for std::num::NonZero<$t> {
+                type Inner = $t;
+
+                fn generic_argument() -> std::any::TypeId {
+                    std::any::TypeId::of::<Self>()
+                }
+
+                fn inner(&self) -> &$t {
+                    unsafe { &*std::ptr::from_ref(self).cast::<$t>() }
+                }
+
+                fn wrap(inner: $t) -> Result<Self, Error> {
+                    std::num::NonZero::new(inner).ok_or(Error::InvalidValue("zero value for non-zero type."))
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_wrapper!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+pub struct WrapperConverter<T: Wrapper> {
+    converter: Box<dyn Converter<T::Inner>>,
+}
+
+impl<T: Wrapper> WrapperConverter<T> {
+    pub fn new(converter: Box<dyn Converter<T::Inner>>) -> Self {
+        Self { converter: converter }
+    }
+}
+
+impl<T: Wrapper> crate::Converter for WrapperConverter<T> {
+    fn length(&self) -> usize {
+        self.converter.length()
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        T::generic_argument()
+    }
+
+    fn is_zero_sized(&self) -> bool {
+        self.converter.is_zero_sized()
+    }
+}
+
+impl<T: Wrapper> Converter<T> for WrapperConverter<T> {
+    fn encode(&self, allocator: &mut crate::allocator::Allocator, item: &T) -> Result<(), Error> {
+        self.converter.encode(allocator, item.inner())
+    }
+
+    fn encoded_length(&self, item: &T) -> Result<usize, Error> {
+        self.converter.encoded_length(item.inner())
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<T, Error> {
+        T::wrap(self.converter.decode(span)?)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{Error, converter::Converter, decode_limits};
+
+pub struct ZeroSizedConverter<T: 'static> {
+    _t: std::marker::PhantomData<T>,
+}
+
+impl<T> ZeroSizedConverter<T> {
+    pub fn new() -> Self {
+        const { assert!(std::mem::size_of::<T>() == 0, "type is not zero-sized.") };
+        Self { _t: std::marker::PhantomData }
+    }
+}
+
+impl<T> crate::Converter for ZeroSizedConverter<T> {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<T>()
+    }
+
+    fn is_zero_sized(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Default> Converter<T> for ZeroSizedConverter<T> {
+    fn encode(&self, _: &mut crate::allocator::Allocator, _: &T) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<T, Error> {
+        decode_limits::ensure_consumed(span)?;
+        Ok(T::default())
+    }
+
+    fn validate(&self, span: &&[u8]) -> Result<(), Error> {
+        decode_limits::ensure_consumed(span)
+    }
+}
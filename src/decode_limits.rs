@@ -0,0 +1,125 @@
+use crate::Error;
+use std::cell::Cell;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodeLimits {
+    pub max_depth: usize,
+    pub max_collection_length: usize,
+    pub max_string_length: usize,
+    pub max_total_allocation: usize,
+    pub strict: bool,
+}
+
+#[derive(Clone, Copy)]
+struct State {
+    limits: DecodeLimits,
+    depth: usize,
+    allocated: usize,
+}
+
+thread_local! {
+    static STATE: Cell<Option<State>> = const { Cell::new(None) };
+}
+
+struct ScopeGuard {
+    source: Option<State>,
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        STATE.set(self.source);
+    }
+}
+
+pub(crate) struct DepthGuard {
+    active: bool,
+}
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        if self.active {
+            update(|state| state.depth -= 1);
+        }
+    }
+}
+
+impl DecodeLimits {
+    pub fn new() -> Self {
+        Self {
+            max_depth: usize::MAX,
+            max_collection_length: usize::MAX,
+            max_string_length: usize::MAX,
+            max_total_allocation: usize::MAX,
+            strict: false,
+        }
+    }
+
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        let state = State { limits: *self, depth: 0, allocated: 0 };
+        let _guard = ScopeGuard { source: STATE.replace(Some(state)) };
+        f()
+    }
+}
+
+pub(crate) fn scope_with<R>(limits: Option<&DecodeLimits>, f: impl FnOnce() -> R) -> R {
+    match limits {
+        Some(limits) => limits.scope(f),
+        None => f(),
+    }
+}
+
+fn update(f: impl FnOnce(&mut State)) {
+    if let Some(mut state) = STATE.get() {
+        f(&mut state);
+        STATE.set(Some(state));
+    }
+}
+
+pub(crate) fn enter() -> Result<DepthGuard, Error> {
+    let Some(state) = STATE.get() else {
+        return Ok(DepthGuard { active: false });
+    };
+    if state.depth >= state.limits.max_depth {
+        return Err(Error::LimitExceeded("nesting depth exceeds limit."));
+    }
+    update(|state| state.depth += 1);
+    Ok(DepthGuard { active: true })
+}
+
+pub(crate) fn ensure_collection_length(length: usize) -> Result<(), Error> {
+    match STATE.get() {
+        Some(state) if length > state.limits.max_collection_length => Err(Error::LimitExceeded("collection length exceeds limit.")),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn ensure_string_length(length: usize) -> Result<(), Error> {
+    match STATE.get() {
+        Some(state) if length > state.limits.max_string_length => Err(Error::LimitExceeded("string length exceeds limit.")),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn allocate(length: usize) -> Result<(), Error> {
+    let Some(state) = STATE.get() else {
+        return Ok(());
+    };
+    match state.allocated.checked_add(length) {
+        Some(allocated) if allocated <= state.limits.max_total_allocation => {
+            update(|state| state.allocated = allocated);
+            Ok(())
+        }
+        _ => Err(Error::LimitExceeded("total allocation exceeds limit.")),
+    }
+}
+
+pub(crate) fn is_strict() -> bool {
+    STATE.get().is_some_and(|state| state.limits.strict)
+}
+
+pub(crate) fn ensure_consumed(span: &[u8]) -> Result<(), Error> {
+    if span.is_empty() == false && is_strict() {
+        return Err(Error::TrailingBytes);
+    }
+    Ok(())
+}
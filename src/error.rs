@@ -0,0 +1,155 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    Index(usize),
+    Field(std::borrow::Cow<'static, str>),
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    NotEnoughBytes,
+    NotEnoughBytesToWrite,
+    MaxCapacityOverflow,
+    AllocationFailed,
+    InvalidAllocator,
+    InvalidLengthPrefix,
+    TrailingBytes,
+    InvalidUtf8(std::str::Utf8Error),
+    DuplicateElement,
+    InvalidValue(&'static str),
+    LimitExceeded(&'static str),
+    Io(std::io::Error),
+    Custom(Box<dyn std::error::Error + Send + Sync>),
+    Context { error: Box<Error>, offset: usize, path: Vec<PathSegment> },
+}
+
+impl Error {
+    pub fn custom<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> Self {
+        Error::Custom(error.into())
+    }
+
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context { error, .. } => error,
+            _ => self,
+        }
+    }
+
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Error::Context { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
+
+    pub fn path(&self) -> String {
+        let mut result = String::new();
+        if let Error::Context { path, .. } = self {
+            for segment in path.iter().rev() {
+                match segment {
+                    PathSegment::Index(index) => result.push_str(&format!("[{index}]")),
+                    PathSegment::Field(name) if result.is_empty() => result.push_str(name),
+                    PathSegment::Field(name) => result.push_str(&format!(".{name}")),
+                }
+            }
+        }
+        result
+    }
+
+    pub fn with_offset(self, offset: usize) -> Self {
+        match self {
+            Error::Context { error, offset: source, path } => Error::Context {
+                error: error,
+                offset: source + offset,
+                path: path,
+            },
+            _ => Error::Context {
+                error: Box::new(self),
+                offset: offset,
+                path: Vec::new(),
+            },
+        }
+    }
+
+    pub fn with_index(self, index: usize) -> Self {
+        self.with_segment(PathSegment::Index(index))
+    }
+
+    pub fn with_field<N: Into<std::borrow::Cow<'static, str>>>(self, name: N) -> Self {
+        self.with_segment(PathSegment::Field(name.into()))
+    }
+
+    fn with_segment(self, segment: PathSegment) -> Self {
+        match self.with_offset(0) {
+            Error::Context { error, offset, mut path } => {
+                path.push(segment);
+                Error::Context { error: error, offset: offset, path: path }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotEnoughBytes => write!(f, "not enough bytes."),
+            Error::NotEnoughBytesToWrite => write!(f, "not enough bytes to write."),
+            Error::MaxCapacityOverflow => write!(f, "maximum capacity has been reached."),
+            Error::AllocationFailed => write!(f, "out of memory."),
+            Error::InvalidAllocator => write!(f, "allocator has been modified unexpectedly!"),
+            Error::InvalidLengthPrefix => write!(f, "invalid length prefix."),
+            Error::TrailingBytes => write!(f, "trailing bytes."),
+            Error::InvalidUtf8(error) => write!(f, "invalid utf-8 bytes: {error}"),
+            Error::DuplicateElement => write!(f, "duplicate element."),
+            Error::InvalidValue(message) => write!(f, "{message}"),
+            Error::LimitExceeded(message) => write!(f, "{message}"),
+            Error::Io(error) => write!(f, "{error}"),
+            Error::Custom(error) => write!(f, "{error}"),
+            Error::Context { error, offset, path } if path.is_empty() => write!(f, "{error} (offset: {offset})"),
+            Error::Context { error, offset, .. } => write!(f, "{error} (offset: {offset}, path: {path})", path = self.path()),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidUtf8(error) => Some(error),
+            Error::Io(error) => Some(error),
+            Error::Custom(error) => Some(error.as_ref()),
+            Error::Context { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::str::Utf8Error> for Error {
+    fn from(error: std::str::Utf8Error) -> Self {
+        Error::InvalidUtf8(error)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<Box<dyn std::error::Error + Send + Sync>> for Error {
+    fn from(error: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        Error::Custom(error)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::Custom(message.into())
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Custom(message.into())
+    }
+}
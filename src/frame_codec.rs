@@ -0,0 +1,101 @@
+use crate::{Error, allocator::Allocator, converter, converter::Converter, decode_limits, decode_limits::DecodeLimits, internal::length};
+
+pub const DEFAULT_MAX_FRAME_LENGTH: usize = 8 * 1024 * 1024;
+
+pub fn encode_frame<T>(allocator: &mut Allocator, converter: &dyn Converter<T>, item: &T) -> Result<(), Error> {
+    converter.encode_with_length_prefix(allocator, item)
+}
+
+pub fn frame_length(span: &[u8]) -> Result<Option<usize>, Error> {
+    if span.is_empty() {
+        return Ok(None);
+    }
+    let header_length = if (span[0] & 0x80) == 0 { 1 } else { 4 };
+    if span.len() < header_length {
+        return Ok(None);
+    }
+    let mut offset = 0usize;
+    let length = unsafe { length::decode_length_prefix(span.as_ptr(), &mut offset, header_length)? };
+    Ok(Some(header_length + length))
+}
+
+pub fn decode_frame<'a>(span: &mut &'a [u8]) -> Result<Option<&'a [u8]>, Error> {
+    match frame_length(span)? {
+        Some(length) if length <= span.len() => Ok(Some(converter::decode_with_length_prefix(span)?)),
+        _ => Ok(None),
+    }
+}
+
+pub struct FrameDecoder {
+    buffer: Vec<u8>,
+    offset: usize,
+    max_frame_length: usize,
+    limits: Option<DecodeLimits>,
+}
+
+impl FrameDecoder {
+    pub fn new() -> Self {
+        Self::with_max_frame_length(DEFAULT_MAX_FRAME_LENGTH)
+    }
+
+    pub fn with_max_frame_length(max_frame_length: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            offset: 0,
+            max_frame_length: max_frame_length,
+            limits: None,
+        }
+    }
+
+    pub fn with_limits(max_frame_length: usize, limits: DecodeLimits) -> Self {
+        Self {
+            limits: Some(limits),
+            ..Self::with_max_frame_length(max_frame_length)
+        }
+    }
+
+    pub fn max_frame_length(&self) -> usize {
+        self.max_frame_length
+    }
+
+    pub fn limits(&self) -> Option<&DecodeLimits> {
+        self.limits.as_ref()
+    }
+
+    pub fn buffered(&self) -> usize {
+        self.buffer.len() - self.offset
+    }
+
+    pub fn extend(&mut self, span: &[u8]) {
+        if self.offset != 0 {
+            self.buffer.drain(..self.offset);
+            self.offset = 0;
+        }
+        self.buffer.extend_from_slice(span);
+    }
+
+    pub fn decode_bytes(&mut self) -> Result<Option<&[u8]>, Error> {
+        let mut span = &self.buffer[self.offset..];
+        if let Some(length) = decode_limits::scope_with(self.limits.as_ref(), || frame_length(span))?
+            && length > self.max_frame_length
+        {
+            return Err(Error::LimitExceeded("frame length exceeds limit."));
+        }
+        let source = span.len();
+        match decode_frame(&mut span)? {
+            Some(frame) => {
+                self.offset += source - span.len();
+                Ok(Some(frame))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn decode<T>(&mut self, converter: &dyn Converter<T>) -> Result<Option<T>, Error> {
+        let limits = self.limits;
+        match self.decode_bytes()? {
+            Some(span) => decode_limits::scope_with(limits.as_ref(), || converter.decode(&span)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use crate::internal::{endian, error_helper};
+use crate::{Error, decode_limits, internal::endian};
 
-pub(crate) fn ensure_length_prefix_length(number: usize) -> Result<(), Box<dyn std::error::Error>> {
+pub(crate) fn ensure_length_prefix_length(number: usize) -> Result<(), Error> {
     if number > i32::MAX as usize {
-        return Err(Box::<dyn std::error::Error>::from("number > i32::MAX"));
+        return Err(Error::MaxCapacityOverflow);
     }
     Ok(())
 }
@@ -27,10 +27,10 @@ pub(crate) unsafe fn encode_length_prefix(buffer: *mut u8, number: usize, length
     }
 }
 
-pub(crate) unsafe fn decode_length_prefix(buffer: *const u8, offset: &mut usize, limits: usize) -> Result<usize, Box<dyn std::error::Error>> {
+pub(crate) unsafe fn decode_length_prefix(buffer: *const u8, offset: &mut usize, limits: usize) -> Result<usize, Error> {
     assert!(limits >= *offset);
     if limits == *offset {
-        return Err(error_helper::error_not_enough_bytes());
+        return Err(Error::NotEnoughBytes);
     }
     let source = unsafe { buffer.add(*offset) };
     let header = unsafe { endian::decode_be_unaligned::<u8>(source) } as u32;
@@ -40,9 +40,13 @@ pub(crate) unsafe fn decode_length_prefix(buffer: *const u8, offset: &mut usize,
     }
     assert!(limits >= *offset);
     if limits < *offset + 3 {
-        return Err(error_helper::error_not_enough_bytes());
+        return Err(Error::NotEnoughBytes);
     }
     let result = unsafe { endian::decode_be_unaligned::<u32>(source) };
+    let length = (result & 0x7FFF_FFFF) as usize;
+    if encode_length_prefix_length(length) != 4 && decode_limits::is_strict() {
+        return Err(Error::InvalidLengthPrefix);
+    }
     *offset += 3;
-    return Ok((result & 0x7FFF_FFFF) as usize);
+    return Ok(length);
 }
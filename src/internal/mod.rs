@@ -1,3 +1,2 @@
 pub(crate) mod endian;
-pub(crate) mod error_helper;
 pub(crate) mod length;
@@ -1,14 +1,31 @@
 pub(crate) mod internal;
 
+mod error;
+
+pub use error::{Error, PathSegment};
+
 pub mod converters;
 
 pub mod allocator;
+pub mod allocator_pool;
+pub mod collection_reader;
 pub mod converter;
+pub mod decode_limits;
+pub mod frame_codec;
 pub mod generator;
+pub mod stream_decoder;
+pub mod stream_encoder;
+
+#[cfg(feature = "tokio")]
+pub mod tokio_codec;
 
 pub trait Converter {
     fn length(&self) -> usize;
     fn generic_argument(&self) -> std::any::TypeId;
+
+    fn is_zero_sized(&self) -> bool {
+        false
+    }
 }
 
 pub trait Generator {
@@ -0,0 +1,89 @@
+use crate::{Error, converter::Converter, decode_limits, decode_limits::DecodeLimits, frame_codec, internal::length};
+use std::io::Read;
+
+pub struct StreamDecoder<R: Read> {
+    reader: R,
+    buffer: Vec<u8>,
+    max_message_length: usize,
+    limits: Option<DecodeLimits>,
+}
+
+impl<R: Read> StreamDecoder<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_max_message_length(reader, frame_codec::DEFAULT_MAX_FRAME_LENGTH)
+    }
+
+    pub fn with_max_message_length(reader: R, max_message_length: usize) -> Self {
+        Self {
+            reader: reader,
+            buffer: Vec::new(),
+            max_message_length: max_message_length,
+            limits: None,
+        }
+    }
+
+    pub fn with_limits(reader: R, max_message_length: usize, limits: DecodeLimits) -> Self {
+        Self {
+            limits: Some(limits),
+            ..Self::with_max_message_length(reader, max_message_length)
+        }
+    }
+
+    pub fn max_message_length(&self) -> usize {
+        self.max_message_length
+    }
+
+    pub fn limits(&self) -> Option<&DecodeLimits> {
+        self.limits.as_ref()
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    pub fn read_bytes(&mut self) -> Result<Option<&[u8]>, Error> {
+        let mut header = [0u8; 4];
+        loop {
+            match self.reader.read(&mut header[..1]) {
+                Ok(0) => return Ok(None),
+                Ok(_) => break,
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(Error::Io(error)),
+            }
+        }
+        let header_length = if (header[0] & 0x80) == 0 { 1 } else { 4 };
+        self.read_exact(&mut header[1..header_length])?;
+        let mut offset = 0usize;
+        let length = decode_limits::scope_with(self.limits.as_ref(), || unsafe { length::decode_length_prefix(header.as_ptr(), &mut offset, header_length) })?;
+        assert!(offset == header_length);
+        if length > self.max_message_length {
+            return Err(Error::LimitExceeded("message length exceeds limit."));
+        }
+        self.buffer.clear();
+        let received = (&mut self.reader).take(length as u64).read_to_end(&mut self.buffer)?;
+        if received != length {
+            return Err(Error::NotEnoughBytes.with_offset(header_length + received));
+        }
+        Ok(Some(&self.buffer))
+    }
+
+    pub fn decode<T>(&mut self, converter: &dyn Converter<T>) -> Result<Option<T>, Error> {
+        let limits = self.limits;
+        match self.read_bytes()? {
+            Some(span) => decode_limits::scope_with(limits.as_ref(), || converter.decode(&span)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn read_exact(&mut self, span: &mut [u8]) -> Result<(), Error> {
+        match self.reader.read_exact(span) {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => Err(Error::NotEnoughBytes.with_offset(0)),
+            Err(error) => Err(Error::Io(error)),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{Error, allocator::Allocator, converter::Converter};
+use std::io::Write;
+
+/// Buffered bytes are written on drop, but write errors are discarded there. Call `flush` or `into_inner` to observe them.
+pub struct StreamEncoder<W: Write> {
+    writer: Option<W>,
+    allocator: Allocator,
+    written: usize,
+    threshold: usize,
+}
+
+impl<W: Write> StreamEncoder<W> {
+    const DEFAULT_THRESHOLD: usize = 64 * 1024;
+
+    pub fn new(writer: W) -> Self {
+        Self::with_threshold(writer, Self::DEFAULT_THRESHOLD)
+    }
+
+    pub fn with_threshold(writer: W, threshold: usize) -> Self {
+        Self {
+            writer: Some(writer),
+            allocator: Allocator::new(),
+            written: 0,
+            threshold: threshold,
+        }
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    pub fn buffered(&self) -> usize {
+        self.allocator.length() - self.written
+    }
+
+    pub fn get_ref(&self) -> &W {
+        self.writer.as_ref().unwrap()
+    }
+
+    pub fn encode<T>(&mut self, converter: &dyn Converter<T>, item: &T) -> Result<(), Error> {
+        converter.encode_with_length_prefix(&mut self.allocator, item)?;
+        if self.buffered() >= self.threshold {
+            self.flush_buffer()?;
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.flush_buffer()?;
+        self.writer.as_mut().unwrap().flush()?;
+        Ok(())
+    }
+
+    pub fn into_inner(mut self) -> Result<W, Error> {
+        self.flush()?;
+        Ok(self.writer.take().unwrap())
+    }
+
+    fn flush_buffer(&mut self) -> Result<(), Error> {
+        let writer = self.writer.as_mut().unwrap();
+        while self.written < self.allocator.length() {
+            match writer.write(&self.allocator[self.written..]) {
+                Ok(0) => return Err(Error::Io(std::io::Error::from(std::io::ErrorKind::WriteZero))),
+                Ok(length) => self.written += length,
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(Error::Io(error)),
+            }
+        }
+        self.allocator.clear();
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl<W: Write> Drop for StreamEncoder<W> {
+    fn drop(&mut self) {
+        if self.writer.is_some() {
+            let _ = self.flush_buffer();
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{Error, allocator::Allocator, converter::Converter, decode_limits, decode_limits::DecodeLimits, frame_codec};
+use bytes::{Buf, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+pub struct ConverterCodec<T> {
+    converter: Box<dyn Converter<T>>,
+    allocator: Allocator,
+    max_frame_length: usize,
+    limits: Option<DecodeLimits>,
+}
+
+impl<T> ConverterCodec<T> {
+    const MAX_RESERVE: usize = 64 * 1024;
+
+    pub fn new(converter: Box<dyn Converter<T>>) -> Self {
+        Self::with_max_frame_length(converter, frame_codec::DEFAULT_MAX_FRAME_LENGTH)
+    }
+
+    pub fn with_max_frame_length(converter: Box<dyn Converter<T>>, max_frame_length: usize) -> Self {
+        Self {
+            converter: converter,
+            allocator: Allocator::new(),
+            max_frame_length: max_frame_length,
+            limits: None,
+        }
+    }
+
+    pub fn with_limits(converter: Box<dyn Converter<T>>, max_frame_length: usize, limits: DecodeLimits) -> Self {
+        Self {
+            limits: Some(limits),
+            ..Self::with_max_frame_length(converter, max_frame_length)
+        }
+    }
+
+    pub fn max_frame_length(&self) -> usize {
+        self.max_frame_length
+    }
+
+    pub fn limits(&self) -> Option<&DecodeLimits> {
+        self.limits.as_ref()
+    }
+}
+
+impl<T> Decoder for ConverterCodec<T> {
+    type Item = T;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let Some(length) = decode_limits::scope_with(self.limits.as_ref(), || frame_codec::frame_length(src))? else {
+            return Ok(None);
+        };
+        if length > self.max_frame_length {
+            return Err(Error::LimitExceeded("frame length exceeds limit."));
+        }
+        if src.len() < length {
+            src.reserve((length - src.len()).min(Self::MAX_RESERVE));
+            return Ok(None);
+        }
+        let mut span = &src[..length];
+        let result = decode_limits::scope_with(self.limits.as_ref(), || match frame_codec::decode_frame(&mut span)? {
+            Some(body) => self.converter.decode(&body),
+            None => unreachable!(),
+        });
+        src.advance(length);
+        result.map(Some)
+    }
+}
+
+impl<T> Encoder<T> for ConverterCodec<T> {
+    type Error = Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.allocator.clear();
+        frame_codec::encode_frame(&mut self.allocator, self.converter.as_ref(), &item)?;
+        dst.extend_from_slice(&self.allocator[..]);
+        Ok(())
+    }
+}
@@ -38,3 +38,70 @@ fn allocator_append() -> Result<(), Box<dyn Error>> {
     }
     Ok(())
 }
+
+#[test]
+fn allocator_rollback() -> Result<(), Box<dyn Error>> {
+    let mut allocator = Allocator::new();
+    allocator.append(b"head")?;
+    let savepoint = allocator.savepoint();
+    allocator.append(&b"1".repeat(300))?;
+    assert_eq!(allocator.length(), 304);
+    assert_eq!(allocator.capacity(), 512);
+    allocator.rollback(savepoint)?;
+    assert_eq!(allocator.length(), 4);
+    assert_eq!(allocator.capacity(), 512);
+    assert_eq!(allocator[..], *b"head");
+    allocator.append(b"tail")?;
+    assert_eq!(allocator[..], *b"headtail");
+    Ok(())
+}
+
+#[test]
+fn allocator_rollback_to_empty() -> Result<(), Box<dyn Error>> {
+    let mut allocator = Allocator::new();
+    let savepoint = allocator.savepoint();
+    allocator.append(b"data")?;
+    allocator.rollback(savepoint)?;
+    assert_eq!(allocator.length(), 0);
+    assert_eq!(allocator.capacity(), 256);
+    assert_eq!(allocator[..].len(), 0);
+    Ok(())
+}
+
+#[test]
+fn allocator_rollback_invalid() -> Result<(), Box<dyn Error>> {
+    let mut allocator = Allocator::new();
+    allocator.append(b"data")?;
+    let savepoint = allocator.savepoint();
+    let mut another = Allocator::new();
+    let result = another.rollback(savepoint);
+    assert!(matches!(result.unwrap_err(), binary::Error::InvalidAllocator));
+    assert_eq!(another.length(), 0);
+    Ok(())
+}
+
+#[test]
+fn allocator_rollback_other_allocator_with_fitting_offset() -> Result<(), Box<dyn Error>> {
+    let mut allocator = Allocator::new();
+    allocator.append(b"data")?;
+    let savepoint = allocator.savepoint();
+    let mut another = Allocator::new();
+    another.append(b"other data")?;
+    let result = another.rollback(savepoint);
+    assert!(matches!(result.unwrap_err(), binary::Error::InvalidAllocator));
+    assert_eq!(another[..], *b"other data");
+    Ok(())
+}
+
+#[test]
+fn allocator_rollback_after_clear() -> Result<(), Box<dyn Error>> {
+    let mut allocator = Allocator::new();
+    allocator.append(b"data")?;
+    let savepoint = allocator.savepoint();
+    allocator.clear();
+    allocator.append(b"other data")?;
+    let result = allocator.rollback(savepoint);
+    assert!(matches!(result.unwrap_err(), binary::Error::InvalidAllocator));
+    assert_eq!(allocator[..], *b"other data");
+    Ok(())
+}
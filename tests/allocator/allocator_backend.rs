@@ -0,0 +1,79 @@
+use binary::allocator::Allocator;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    error::Error,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+#[derive(Default)]
+struct CountingState {
+    allocated: AtomicUsize,
+    deallocated: AtomicUsize,
+    live_bytes: AtomicUsize,
+}
+
+struct CountingBackend {
+    state: Arc<CountingState>,
+}
+
+unsafe impl GlobalAlloc for CountingBackend {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.state.allocated.fetch_add(1, Ordering::SeqCst);
+        self.state.live_bytes.fetch_add(layout.size(), Ordering::SeqCst);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.state.deallocated.fetch_add(1, Ordering::SeqCst);
+        self.state.live_bytes.fetch_sub(layout.size(), Ordering::SeqCst);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+struct ExhaustedBackend;
+
+unsafe impl GlobalAlloc for ExhaustedBackend {
+    unsafe fn alloc(&self, _: Layout) -> *mut u8 {
+        std::ptr::null_mut()
+    }
+
+    unsafe fn dealloc(&self, _: *mut u8, _: Layout) {
+        unreachable!()
+    }
+}
+
+#[test]
+fn allocator_backend_counting() -> Result<(), Box<dyn Error>> {
+    let state = Arc::new(CountingState::default());
+    {
+        let mut allocator = Allocator::with_backend(CountingBackend { state: state.clone() });
+        assert_eq!(state.allocated.load(Ordering::SeqCst), 0);
+        allocator.append(b"head")?;
+        assert_eq!(state.allocated.load(Ordering::SeqCst), 1);
+        assert_eq!(state.live_bytes.load(Ordering::SeqCst), 256);
+        allocator.append(&b"1".repeat(1000))?;
+        assert_eq!(state.allocated.load(Ordering::SeqCst), 2);
+        assert_eq!(state.deallocated.load(Ordering::SeqCst), 1);
+        assert_eq!(state.live_bytes.load(Ordering::SeqCst), 1024);
+        assert_eq!(allocator[..4], *b"head");
+        assert_eq!(allocator.length(), 1004);
+    }
+    assert_eq!(state.allocated.load(Ordering::SeqCst), 2);
+    assert_eq!(state.deallocated.load(Ordering::SeqCst), 2);
+    assert_eq!(state.live_bytes.load(Ordering::SeqCst), 0);
+    Ok(())
+}
+
+#[test]
+fn allocator_backend_allocate_failed() {
+    let mut allocator = Allocator::with_backend(ExhaustedBackend);
+    let result = allocator.append(b"data");
+    let error = result.unwrap_err();
+    assert!(matches!(error, binary::Error::AllocationFailed));
+    assert_eq!(error.to_string(), "out of memory.");
+    assert_eq!(allocator.length(), 0);
+    assert_eq!(allocator.capacity(), 0);
+}
@@ -1 +1,2 @@
 mod allocator;
+mod allocator_backend;
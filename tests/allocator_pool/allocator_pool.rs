@@ -0,0 +1,79 @@
+use binary::{allocator_pool::AllocatorPool, converter::Converter, converters::string_converter::StringConverter};
+use std::error::Error;
+
+#[test]
+fn allocator_pool_new() {
+    let pool = AllocatorPool::new(4, 1024);
+    assert_eq!(pool.max_pooled(), 4);
+    assert_eq!(pool.max_retained_capacity(), 1024);
+    assert_eq!(pool.pooled(), 0);
+}
+
+#[test]
+fn allocator_pool_rent_retains_capacity() -> Result<(), Box<dyn Error>> {
+    let pool = AllocatorPool::new(4, 1024);
+    {
+        let mut allocator = pool.rent();
+        allocator.append(&b"1".repeat(300))?;
+        assert_eq!(allocator.capacity(), 512);
+    }
+    assert_eq!(pool.pooled(), 1);
+    let allocator = pool.rent();
+    assert_eq!(pool.pooled(), 0);
+    assert_eq!(allocator.length(), 0);
+    assert_eq!(allocator.capacity(), 512);
+    assert_eq!(allocator[..].len(), 0);
+    Ok(())
+}
+
+#[test]
+fn allocator_pool_drop_oversized() -> Result<(), Box<dyn Error>> {
+    let pool = AllocatorPool::new(4, 256);
+    {
+        let mut allocator = pool.rent();
+        allocator.append(&b"1".repeat(300))?;
+    }
+    assert_eq!(pool.pooled(), 0);
+    {
+        let mut allocator = pool.rent();
+        allocator.append(&b"1".repeat(200))?;
+    }
+    assert_eq!(pool.pooled(), 1);
+    Ok(())
+}
+
+#[test]
+fn allocator_pool_max_pooled() -> Result<(), Box<dyn Error>> {
+    let pool = AllocatorPool::new(2, 1024);
+    {
+        let mut allocators = [pool.rent(), pool.rent(), pool.rent()];
+        for allocator in allocators.iter_mut() {
+            allocator.append(b"data")?;
+        }
+    }
+    assert_eq!(pool.pooled(), 2);
+    Ok(())
+}
+
+#[test]
+fn allocator_pool_threads() {
+    let pool = AllocatorPool::new(8, 1024);
+    let converter = StringConverter::new();
+    std::thread::scope(|scope| {
+        for index in 0..8 {
+            let pool = &pool;
+            let converter = &converter;
+            scope.spawn(move || {
+                for _ in 0..100 {
+                    let mut allocator = pool.rent();
+                    let source = format!("thread {index}");
+                    converter.encode_auto(&mut allocator, &source).unwrap();
+                    let mut span = &allocator[..];
+                    assert_eq!(converter.decode_auto(&mut span).unwrap(), source);
+                }
+            });
+        }
+    });
+    assert!(pool.pooled() >= 1);
+    assert!(pool.pooled() <= 8);
+}
@@ -0,0 +1,128 @@
+use binary::{
+    allocator::Allocator,
+    collection_reader::CollectionReader,
+    converter::Converter,
+    converters::{collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, string_converter::StringConverter, zero_sized_converter::ZeroSizedConverter},
+};
+use std::cell::Cell;
+
+struct CountingConverter {
+    converter: StringConverter,
+    decoded: Cell<usize>,
+}
+
+impl binary::Converter for CountingConverter {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<String>()
+    }
+}
+
+impl Converter<String> for CountingConverter {
+    fn encode(&self, allocator: &mut Allocator, item: &String) -> Result<(), binary::Error> {
+        self.converter.encode(allocator, item)
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<String, binary::Error> {
+        self.decoded.set(self.decoded.get() + 1);
+        self.converter.decode(span)
+    }
+}
+
+fn encode_strings(items: &[&str]) -> Result<Allocator, binary::Error> {
+    let mut allocator = Allocator::new();
+    let converter = CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()));
+    converter.encode_auto(&mut allocator, &items.iter().map(|x| x.to_string()).collect())?;
+    Ok(allocator)
+}
+
+#[test]
+fn reader_iterate() -> Result<(), Box<dyn std::error::Error>> {
+    let allocator = encode_strings(&["Alpha", "Bravo", "Charlie"])?;
+    let converter = StringConverter::new();
+    let mut span = &allocator[..];
+    let reader = CollectionReader::with_length_prefix(&converter, &mut span)?;
+    assert_eq!(span.len(), 0);
+    assert_eq!(reader.size_hint(), (1, Some(20)));
+    let actual = reader.collect::<Result<Vec<String>, binary::Error>>()?;
+    assert_eq!(actual, vec!["Alpha", "Bravo", "Charlie"]);
+    Ok(())
+}
+
+#[test]
+fn reader_skip_without_decode() -> Result<(), Box<dyn std::error::Error>> {
+    let allocator = encode_strings(&["Alpha", "Bravo", "Charlie", "Delta"])?;
+    let converter = CountingConverter {
+        converter: StringConverter::new(),
+        decoded: Cell::new(0),
+    };
+    let mut span = &allocator[..];
+    let mut reader = CollectionReader::with_length_prefix(&converter, &mut span)?.skip(2);
+    assert_eq!(reader.next().unwrap()?, "Charlie");
+    assert_eq!(converter.decoded.get(), 1);
+    let mut span = &allocator[..];
+    let mut reader = CollectionReader::with_length_prefix(&converter, &mut span)?;
+    assert_eq!(reader.nth(3).unwrap()?, "Delta");
+    assert_eq!(reader.index(), 4);
+    assert!(reader.next().is_none());
+    assert_eq!(converter.decoded.get(), 2);
+    Ok(())
+}
+
+#[test]
+fn reader_stop_early() -> Result<(), Box<dyn std::error::Error>> {
+    let allocator = encode_strings(&["Alpha", "Bravo", "Charlie"])?;
+    let converter = StringConverter::new();
+    let mut span = &allocator[..];
+    let mut reader = CollectionReader::with_length_prefix(&converter, &mut span)?;
+    assert_eq!(reader.next().unwrap()?, "Alpha");
+    assert_eq!(reader.index(), 1);
+    assert_eq!(reader.remaining(), b"\x05Bravo\x07Charlie");
+    Ok(())
+}
+
+#[test]
+fn reader_fixed_length() -> Result<(), Box<dyn std::error::Error>> {
+    let buffer = [1u8, 0, 2, 0, 3, 0, 4];
+    let converter = LittleEndianConverter::<u16>::new();
+    let mut reader = CollectionReader::new(&converter, &buffer[..]);
+    assert_eq!(reader.size_hint(), (4, Some(4)));
+    assert_eq!(reader.nth(1).unwrap()?, 2);
+    assert_eq!(reader.size_hint(), (2, Some(2)));
+    assert_eq!(reader.next().unwrap()?, 3);
+    let error = reader.next().unwrap().unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(6));
+    assert_eq!(error.path(), "[3]");
+    assert!(reader.next().is_none());
+    assert_eq!(reader.size_hint(), (0, Some(0)));
+    Ok(())
+}
+
+#[test]
+fn reader_skip_error() {
+    let buffer = [5u8, b'A', b'l', b'p', b'h', b'a', 9, b'B'];
+    let converter = StringConverter::new();
+    let mut reader = CollectionReader::new(&converter, &buffer[..]);
+    let error = reader.nth(2).unwrap().unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(6));
+    assert_eq!(error.path(), "[1]");
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn reader_zero_sized() -> Result<(), Box<dyn std::error::Error>> {
+    let buffer = [3u8];
+    let converter = ZeroSizedConverter::<()>::new();
+    let mut reader = CollectionReader::new(&converter, &buffer[..]);
+    assert_eq!(reader.size_hint(), (0, Some(3)));
+    reader.next().unwrap()?;
+    assert_eq!(reader.nth(1).unwrap()?, ());
+    assert_eq!(reader.index(), 3);
+    assert!(reader.next().is_none());
+    Ok(())
+}
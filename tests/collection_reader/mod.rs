@@ -0,0 +1 @@
+mod collection_reader;
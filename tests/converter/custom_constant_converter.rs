@@ -21,13 +21,13 @@ impl<T> binary::Converter for CustomConstantConverter<T> {
 }
 
 impl<T> Converter<T> for CustomConstantConverter<T> {
-    fn encode(&self, allocator: &mut binary::allocator::Allocator, item: &T) -> Result<(), Box<dyn std::error::Error>> {
+    fn encode(&self, allocator: &mut binary::allocator::Allocator, item: &T) -> Result<(), binary::Error> {
         allocator.append(unsafe { std::slice::from_raw_parts(std::ptr::from_ref(item).cast::<u8>(), std::mem::size_of::<T>()) })
     }
 
-    fn decode(&self, span: &&[u8]) -> Result<T, Box<dyn std::error::Error>> {
+    fn decode(&self, span: &&[u8]) -> Result<T, binary::Error> {
         let part = span.split_at_checked(std::mem::size_of::<T>());
-        let head = part.ok_or(Box::<dyn std::error::Error>::from("not enough bytes for custom constant type."))?.0;
+        let head = part.ok_or(binary::Error::from("not enough bytes for custom constant type."))?.0;
         Ok(unsafe { head.as_ptr().cast::<T>().read_unaligned() })
     }
 }
@@ -84,8 +84,8 @@ fn decode_auto_not_enough_bytes() {
     let buffer = [0u8; 1];
     let mut span = &buffer[..];
     let result = converter.decode_auto(&mut span);
-    let binding = result.unwrap_err();
-    let error = binding.downcast_ref::<std::io::Error>().unwrap();
-    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
-    assert_eq!(error.to_string(), "not enough bytes.")
+    let error = result.unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(0));
+    assert_eq!(error.to_string(), "not enough bytes. (offset: 0)")
 }
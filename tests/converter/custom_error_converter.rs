@@ -0,0 +1,67 @@
+use binary::{
+    allocator::Allocator,
+    converter::Converter,
+    converters::{collection_converter::CollectionConverter, string_converter::StringConverter},
+};
+
+struct CustomErrorConverter {
+    converter: StringConverter,
+}
+
+impl CustomErrorConverter {
+    fn new() -> Self {
+        Self { converter: StringConverter::new() }
+    }
+}
+
+impl binary::Converter for CustomErrorConverter {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<String>()
+    }
+}
+
+impl Converter<String> for CustomErrorConverter {
+    fn encode(&self, allocator: &mut Allocator, item: &String) -> Result<(), binary::Error> {
+        self.converter.encode(allocator, item)?;
+        if item == "error" {
+            return Err(binary::Error::from("custom error after partial write."));
+        }
+        Ok(())
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<String, binary::Error> {
+        self.converter.decode(span)
+    }
+}
+
+#[test]
+fn encode_with_length_prefix_error_rollback() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = CustomErrorConverter::new();
+    converter.encode_with_length_prefix(&mut allocator, &"Alpha".to_string())?;
+    let result = converter.encode_with_length_prefix(&mut allocator, &"error".to_string());
+    assert_eq!(result.unwrap_err().to_string(), "custom error after partial write.");
+    assert_eq!(allocator.length(), 6);
+    converter.encode_with_length_prefix(&mut allocator, &"Bravo".to_string())?;
+    assert_eq!(allocator[..], *b"\x05Alpha\x05Bravo");
+    Ok(())
+}
+
+#[test]
+fn collection_encode_error_rollback() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = CollectionConverter::<Vec<String>>::new(Box::new(CustomErrorConverter::new()));
+    converter.encode_auto(&mut allocator, &vec!["Alpha".to_string()])?;
+    let length = allocator.length();
+    let result = converter.encode_auto(&mut allocator, &vec!["Bravo".to_string(), "error".to_string()]);
+    assert_eq!(result.unwrap_err().to_string(), "custom error after partial write.");
+    assert_eq!(allocator.length(), length);
+    let mut span = &allocator[..];
+    assert_eq!(converter.decode_auto(&mut span)?, vec!["Alpha".to_string()]);
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
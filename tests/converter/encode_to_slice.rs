@@ -0,0 +1,72 @@
+use binary::{
+    allocator::Allocator,
+    converter::{BorrowConverter, Converter},
+    converters::{
+        borrowed_string_converter::BorrowedStringConverter, collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, result_converter::ResultConverter, string_converter::StringConverter,
+        zero_sized_converter::ZeroSizedConverter,
+    },
+};
+
+fn test_encode_to_slice<T>(converter: &dyn Converter<T>, item: &T) -> Result<(), binary::Error> {
+    let mut allocator = Allocator::new();
+    converter.encode(&mut allocator, item)?;
+    let expected = &allocator[..];
+    let mut buffer = vec![0xCCu8; expected.len() + 8];
+    assert_eq!(converter.encode_to_slice(&mut buffer[..expected.len()], item)?, expected.len());
+    assert_eq!(&buffer[..expected.len()], expected);
+    assert!(buffer[expected.len()..].iter().all(|x| *x == 0xCC));
+    assert_eq!(converter.encode_to_slice(&mut buffer, item)?, expected.len());
+    assert_eq!(&buffer[..expected.len()], expected);
+    for length in 0..expected.len() {
+        let error = converter.encode_to_slice(&mut buffer[..length], item).unwrap_err();
+        assert!(matches!(error, binary::Error::NotEnoughBytesToWrite));
+    }
+    Ok(())
+}
+
+#[test]
+fn encode_to_slice_fixed() -> Result<(), binary::Error> {
+    test_encode_to_slice(&LittleEndianConverter::<u32>::new(), &0x1234_5678)?;
+    test_encode_to_slice(&ZeroSizedConverter::<()>::new(), &())?;
+    Ok(())
+}
+
+#[test]
+fn encode_to_slice_string() -> Result<(), binary::Error> {
+    for length in [0, 1, 16, 17, 300] {
+        test_encode_to_slice(&StringConverter::new(), &"a".repeat(length))?;
+    }
+    Ok(())
+}
+
+#[test]
+fn encode_to_slice_collection() -> Result<(), binary::Error> {
+    let converter = CollectionConverter::<Vec<i64>>::new(Box::new(LittleEndianConverter::new()));
+    test_encode_to_slice(&converter, &(0..50).collect())?;
+    let converter = CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()));
+    test_encode_to_slice(&converter, &(0..40).map(|x| "b".repeat(x)).collect())?;
+    let converter = CollectionConverter::<Vec<Vec<String>>>::new(Box::new(CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()))));
+    test_encode_to_slice(&converter, &vec![vec![], vec!["c".repeat(20); 3], vec![String::new(); 2]])?;
+    let converter = CollectionConverter::<Vec<()>>::new(Box::new(ZeroSizedConverter::new()));
+    test_encode_to_slice(&converter, &vec![(); 200])?;
+    Ok(())
+}
+
+#[test]
+fn encode_to_slice_result() -> Result<(), binary::Error> {
+    let converter = ResultConverter::<String, Vec<String>>::new(Box::new(StringConverter::new()), Box::new(CollectionConverter::new(Box::new(StringConverter::new()))));
+    test_encode_to_slice(&converter, &Ok("d".repeat(17)))?;
+    test_encode_to_slice(&converter, &Err(vec!["e".repeat(30), String::new()]))?;
+    Ok(())
+}
+
+#[test]
+fn encode_to_slice_borrowed() -> Result<(), binary::Error> {
+    let converter = BorrowedStringConverter::new();
+    let mut buffer = [0u8; 8];
+    assert_eq!(BorrowConverter::encode_to_slice(&converter, &mut buffer, &"Alpha")?, 5);
+    assert_eq!(&buffer[..5], b"Alpha");
+    let error = BorrowConverter::encode_to_slice(&converter, &mut buffer, &"Alpha Bravo").unwrap_err();
+    assert!(matches!(error, binary::Error::NotEnoughBytesToWrite));
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use std::{borrow::Cow, collections::VecDeque, net::IpAddr, net::SocketAddr, rc::Rc, time::Duration};
+
+use binary::{
+    allocator::Allocator,
+    converter::{BorrowConverter, Converter},
+    converters::{
+        borrowed_bytes_converter::BorrowedBytesConverter, bytes_converter::BytesConverter, collection_converter::CollectionConverter, cow_string_converter::CowStringConverter, duration_converter::DurationConverter,
+        ip_address_converter::IpAddrConverter, little_endian_converter::LittleEndianConverter, range_converter::RangeConverter, range_converter::RangeInclusiveConverter, result_converter::ResultConverter, slice_converter::SliceConverter,
+        socket_address_converter::SocketAddrConverter, string_converter::StringConverter, wrapper_converter::WrapperConverter, zero_sized_converter::ZeroSizedConverter,
+    },
+};
+
+fn test_encoded_length<T>(converter: &dyn Converter<T>, item: &T) -> Result<usize, binary::Error> {
+    let mut allocator = Allocator::new();
+    converter.encode(&mut allocator, item)?;
+    assert_eq!(converter.encoded_length(item)?, allocator.length());
+    let mut allocator = Allocator::new();
+    converter.encode_auto(&mut allocator, item)?;
+    assert_eq!(converter.encoded_length_auto(item)?, allocator.length());
+    let mut allocator = Allocator::new();
+    converter.encode_with_length_prefix(&mut allocator, item)?;
+    assert_eq!(converter.encoded_length_with_length_prefix(item)?, allocator.length());
+    converter.encoded_length(item)
+}
+
+#[test]
+fn encoded_length_fixed() -> Result<(), binary::Error> {
+    let converter = LittleEndianConverter::<u64>::new();
+    assert_eq!(test_encoded_length(&converter, &0)?, 8);
+    assert_eq!(converter.encoded_length_with_length_prefix(&0)?, 9);
+    let converter = ZeroSizedConverter::<()>::new();
+    assert_eq!(test_encoded_length(&converter, &())?, 0);
+    Ok(())
+}
+
+#[test]
+fn encoded_length_string() -> Result<(), binary::Error> {
+    let converter = StringConverter::new();
+    for length in [0, 1, 15, 16, 17, 127, 128, 1024] {
+        let item = "a".repeat(length);
+        assert_eq!(test_encoded_length(&converter, &item)?, length);
+    }
+    assert_eq!(converter.encoded_length_with_length_prefix(&"a".repeat(127))?, 128);
+    assert_eq!(converter.encoded_length_with_length_prefix(&"a".repeat(128))?, 132);
+    Ok(())
+}
+
+#[test]
+fn encoded_length_collection() -> Result<(), binary::Error> {
+    let converter = CollectionConverter::<Vec<i32>>::new(Box::new(LittleEndianConverter::new()));
+    assert_eq!(test_encoded_length(&converter, &(0..100).collect())?, 400);
+    let converter = CollectionConverter::<VecDeque<i32>>::new(Box::new(LittleEndianConverter::new()));
+    assert_eq!(test_encoded_length(&converter, &(0..100).collect())?, 400);
+    let converter = CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()));
+    let item = (0..40).map(|x| "b".repeat(x)).collect::<Vec<_>>();
+    assert_eq!(test_encoded_length(&converter, &item)?, (0..40).map(|x| 1 + x).sum::<usize>());
+    let converter = CollectionConverter::<Vec<Vec<String>>>::new(Box::new(CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()))));
+    test_encoded_length(&converter, &vec![vec![], item.clone(), vec![String::new(); 3]])?;
+    let converter = CollectionConverter::<Vec<()>>::new(Box::new(ZeroSizedConverter::new()));
+    assert_eq!(test_encoded_length(&converter, &vec![(); 127])?, 1);
+    assert_eq!(test_encoded_length(&converter, &vec![(); 128])?, 4);
+    let converter = SliceConverter::<Rc<[String]>, String>::new(Box::new(StringConverter::new()));
+    test_encoded_length(&converter, &item.into())?;
+    Ok(())
+}
+
+fn test_borrow_encoded_length<'de, T>(converter: &dyn BorrowConverter<'de, T>, item: &T) -> Result<usize, binary::Error> {
+    let mut allocator = Allocator::new();
+    converter.encode(&mut allocator, item)?;
+    assert_eq!(converter.encoded_length(item)?, allocator.length());
+    let mut allocator = Allocator::new();
+    converter.encode_with_length_prefix(&mut allocator, item)?;
+    assert_eq!(converter.encoded_length_with_length_prefix(item)?, allocator.length());
+    converter.encoded_length(item)
+}
+
+#[test]
+fn encoded_length_default() -> Result<(), binary::Error> {
+    let converter = DurationConverter::new();
+    assert_eq!(test_encoded_length(&converter, &Duration::from_millis(1500))?, 12);
+    Ok(())
+}
+
+#[test]
+fn encoded_length_overrides() -> Result<(), binary::Error> {
+    let converter = IpAddrConverter::new();
+    assert_eq!(test_encoded_length(&converter, &"127.0.0.1".parse::<IpAddr>().unwrap())?, 4);
+    assert_eq!(test_encoded_length(&converter, &"::1".parse::<IpAddr>().unwrap())?, 16);
+    let converter = ResultConverter::<String, i32>::new(Box::new(StringConverter::new()), Box::new(LittleEndianConverter::new()));
+    assert_eq!(test_encoded_length(&converter, &Ok("a".repeat(20)))?, 22);
+    assert_eq!(test_encoded_length(&converter, &Err(-1))?, 5);
+    let converter = WrapperConverter::<Box<String>>::new(Box::new(StringConverter::new()));
+    assert_eq!(test_encoded_length(&converter, &Box::new("a".repeat(200)))?, 200);
+    let converter = BytesConverter::<Vec<u8>>::new();
+    assert_eq!(test_encoded_length(&converter, &vec![7u8; 300])?, 300);
+    let converter = BytesConverter::<Box<[u8]>>::new();
+    assert_eq!(test_encoded_length(&converter, &Box::from(&[7u8; 3][..]))?, 3);
+    let converter = BytesConverter::<[u8; 5]>::new();
+    assert_eq!(test_encoded_length(&converter, &[7u8; 5])?, 5);
+    let converter = RangeConverter::<String>::new(Box::new(StringConverter::new()));
+    assert_eq!(test_encoded_length(&converter, &("a".to_string().."b".repeat(200)))?, 206);
+    let converter = RangeInclusiveConverter::<i32>::new(Box::new(LittleEndianConverter::new()));
+    assert_eq!(test_encoded_length(&converter, &(1..=9))?, 8);
+    let converter = SocketAddrConverter::new();
+    assert_eq!(test_encoded_length(&converter, &"127.0.0.1:80".parse::<SocketAddr>().unwrap())?, 6);
+    assert_eq!(test_encoded_length(&converter, &"[::1]:80".parse::<SocketAddr>().unwrap())?, 18);
+    assert_eq!(test_encoded_length(&converter, &"[fe80::1%3]:80".parse::<SocketAddr>().unwrap())?, 26);
+    let converter = CowStringConverter::new();
+    assert_eq!(test_borrow_encoded_length(&converter, &Cow::Borrowed("Alpha"))?, 5);
+    let converter = BorrowedBytesConverter::new();
+    assert_eq!(test_borrow_encoded_length(&converter, &&b"Bravo"[..])?, 5);
+    Ok(())
+}
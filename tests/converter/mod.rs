@@ -1 +1,6 @@
 mod custom_constant_converter;
+mod custom_error_converter;
+mod encode_to_slice;
+mod encoded_length;
+mod peek_and_skip;
+mod validate;
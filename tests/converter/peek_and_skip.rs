@@ -0,0 +1,56 @@
+use binary::{
+    allocator::Allocator,
+    converter::{self, Converter},
+    converters::{little_endian_converter::LittleEndianConverter, string_converter::StringConverter, zero_sized_converter::ZeroSizedConverter},
+};
+
+#[test]
+fn peek_length() -> Result<(), binary::Error> {
+    for length in [0, 1, 16, 17, 127, 128, 4096] {
+        let mut allocator = Allocator::new();
+        StringConverter::new().encode_with_length_prefix(&mut allocator, &"a".repeat(length))?;
+        let span = &allocator[..];
+        assert_eq!(converter::peek_length(span)?, length);
+        assert_eq!(converter::peek_length(&span[..if length < 128 { 1 } else { 4 }])?, length);
+    }
+    Ok(())
+}
+
+#[test]
+fn peek_length_not_enough_bytes() {
+    assert!(matches!(converter::peek_length(&[]).unwrap_err(), binary::Error::NotEnoughBytes));
+    assert!(matches!(converter::peek_length(&[0x80, 0, 0]).unwrap_err(), binary::Error::NotEnoughBytes));
+}
+
+#[test]
+fn skip_leading_fields() -> Result<(), binary::Error> {
+    let mut allocator = Allocator::new();
+    LittleEndianConverter::<u64>::new().encode_auto(&mut allocator, &7)?;
+    StringConverter::new().encode_auto(&mut allocator, &"Alpha".repeat(20))?;
+    ZeroSizedConverter::<()>::new().encode_auto(&mut allocator, &())?;
+    StringConverter::new().encode_auto(&mut allocator, &"Bravo".to_string())?;
+    let mut span = &allocator[..];
+    converter::skip_auto(&LittleEndianConverter::<u64>::new(), &mut span)?;
+    assert_eq!(span.len(), 107);
+    converter::skip_auto(&StringConverter::new(), &mut span)?;
+    assert_eq!(span.len(), 6);
+    converter::skip_auto(&ZeroSizedConverter::<()>::new(), &mut span)?;
+    assert_eq!(span.len(), 6);
+    assert_eq!(StringConverter::new().decode_auto(&mut span)?, "Bravo");
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn skip_not_enough_bytes() -> Result<(), binary::Error> {
+    let mut span = &[1u8, 2, 3][..];
+    assert!(matches!(converter::skip_auto(&LittleEndianConverter::<u32>::new(), &mut span).unwrap_err(), binary::Error::NotEnoughBytes));
+    assert_eq!(span.len(), 3);
+    let mut span = &[0x05u8, b'A', b'l'][..];
+    assert!(matches!(converter::skip_with_length_prefix(&mut span).unwrap_err(), binary::Error::NotEnoughBytes));
+    assert_eq!(span.len(), 3);
+    let mut span = &[0x02u8, b'A', b'l', 0x00][..];
+    converter::skip_with_length_prefix(&mut span)?;
+    assert_eq!(span, &[0x00]);
+    Ok(())
+}
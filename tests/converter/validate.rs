@@ -0,0 +1,109 @@
+use std::{collections::HashSet, ops::Range};
+
+use binary::{
+    allocator::Allocator,
+    converter::{self, Converter},
+    converters::{
+        bytes_converter::BytesConverter, collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, range_converter::RangeConverter, result_converter::ResultConverter, string_converter::StringConverter,
+        zero_sized_converter::ZeroSizedConverter,
+    },
+};
+
+fn encode<T>(converter: &dyn Converter<T>, item: &T) -> Vec<u8> {
+    let mut allocator = Allocator::new();
+    converter.encode(&mut allocator, item).unwrap();
+    allocator[..].to_vec()
+}
+
+fn test_validate<T>(converter: &dyn Converter<T>, span: &[u8]) {
+    let expected = converter.decode(&span).map(|_| ()).map_err(|error| error.to_string());
+    let actual = converter.validate(&span).map_err(|error| error.to_string());
+    assert_eq!(actual, expected);
+    let mut allocator = Allocator::new();
+    converter::encode(&mut allocator, span.len()).unwrap();
+    allocator.append(span).unwrap();
+    allocator.append(&[0xCC]).unwrap();
+    let mut body = &allocator[..];
+    let expected = converter.decode_with_length_prefix(&mut body).map(|_| body.len()).map_err(|error| error.to_string());
+    let mut body = &allocator[..];
+    let actual = converter.validate_with_length_prefix(&mut body).map(|_| body.len()).map_err(|error| error.to_string());
+    assert_eq!(actual, expected);
+    let mut body = &allocator[..];
+    let expected = converter.decode_auto(&mut body).map(|_| body.len()).map_err(|error| error.to_string());
+    let mut body = &allocator[..];
+    let actual = converter.validate_auto(&mut body).map(|_| body.len()).map_err(|error| error.to_string());
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn validate_string() {
+    let converter = StringConverter::new();
+    test_validate(&converter, b"Alpha");
+    test_validate(&converter, &[]);
+    test_validate(&converter, &[0xC3, 0x28]);
+    assert!(matches!(converter.validate(&&[0xC3u8, 0x28][..]).unwrap_err(), binary::Error::InvalidUtf8(_)));
+}
+
+#[test]
+fn validate_collection() {
+    let converter = CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()));
+    let mut buffer = encode(&converter, &vec!["Alpha".to_string(), "Bravo".repeat(10), String::new()]);
+    test_validate(&converter, &buffer);
+    buffer[3] = 0xFF;
+    test_validate(&converter, &buffer);
+    let error = converter.validate(&buffer.as_slice()).unwrap_err();
+    assert_eq!(error.offset(), Some(1));
+    assert_eq!(error.path(), "[0]");
+    test_validate(&converter, &buffer[..20]);
+
+    let converter = CollectionConverter::<Vec<i32>>::new(Box::new(LittleEndianConverter::new()));
+    let buffer = encode(&converter, &(0..10).collect());
+    test_validate(&converter, &buffer);
+    test_validate(&converter, &buffer[..39]);
+
+    let converter = CollectionConverter::<Vec<()>>::new(Box::new(ZeroSizedConverter::new()));
+    test_validate(&converter, &encode(&converter, &vec![(); 300]));
+    test_validate(&converter, &[0x80, 0x00]);
+}
+
+#[test]
+fn validate_nested_collection() {
+    let converter = CollectionConverter::<Vec<Vec<String>>>::new(Box::new(CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()))));
+    let mut buffer = encode(&converter, &vec![vec![], vec!["Alpha".to_string(), "Bravo".to_string()]]);
+    test_validate(&converter, &buffer);
+    let last = buffer.len() - 1;
+    buffer[last] = 0xFF;
+    test_validate(&converter, &buffer);
+    assert_eq!(converter.validate(&buffer.as_slice()).unwrap_err().path(), "[1][1]");
+}
+
+#[test]
+fn validate_unique_collection() {
+    let converter = CollectionConverter::<HashSet<i32>>::with_unique_elements(Box::new(LittleEndianConverter::new()));
+    let buffer = encode(&CollectionConverter::<Vec<i32>>::new(Box::new(LittleEndianConverter::new())), &vec![1, 2, 1]);
+    test_validate(&converter, &buffer);
+    assert!(matches!(converter.validate(&buffer.as_slice()).unwrap_err().root(), binary::Error::DuplicateElement));
+}
+
+#[test]
+fn validate_range_and_result() {
+    let converter = RangeConverter::<String>::new(Box::new(StringConverter::new()));
+    let mut buffer = encode(
+        &converter,
+        &Range {
+            start: "Alpha".to_string(),
+            end: "Bravo".to_string(),
+        },
+    );
+    test_validate(&converter, &buffer);
+    buffer[7] = 0xFF;
+    test_validate(&converter, &buffer);
+    assert_eq!(converter.validate(&buffer.as_slice()).unwrap_err().path(), "end");
+
+    let converter = ResultConverter::<String, Vec<u8>>::new(Box::new(StringConverter::new()), Box::new(BytesConverter::<Vec<u8>>::new()));
+    test_validate(&converter, &encode(&converter, &Ok("Alpha".to_string())));
+    test_validate(&converter, &encode(&converter, &Err(vec![0xFF; 20])));
+    test_validate(&converter, &[0x00, 0x02, 0xFF, 0xFF]);
+    test_validate(&converter, &[0x02, 0x00]);
+    test_validate(&converter, &[]);
+}
@@ -0,0 +1,51 @@
+use binary::{Converter, allocator::Allocator, converter::BorrowConverter, converters::borrowed_bytes_converter::BorrowedBytesConverter};
+
+#[test]
+fn base_info() {
+    let converter = BorrowedBytesConverter::new();
+    assert_eq!(converter.length(), 0);
+    assert_eq!(converter.generic_argument(), std::any::TypeId::of::<&'static [u8]>())
+}
+
+#[test]
+fn base_methods() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = BorrowedBytesConverter::new();
+    let source: &[u8] = &[1, 2, 3, 255];
+    converter.encode(&mut allocator, &source)?;
+    assert_eq!(allocator[..], *source);
+    let span = &allocator[..];
+    let actual = converter.decode(span)?;
+    assert_eq!(actual, source);
+    assert_eq!(actual.as_ptr(), span.as_ptr());
+    Ok(())
+}
+
+#[test]
+fn length_prefix_methods() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = BorrowedBytesConverter::new();
+    let empty: &[u8] = &[];
+    let source: &[u8] = &[0u8; 300];
+    converter.encode_with_length_prefix(&mut allocator, &empty)?;
+    converter.encode_with_length_prefix(&mut allocator, &source)?;
+    assert_eq!(allocator.length(), 1 + 4 + 300);
+    let buffer = &allocator[..];
+    let mut span = buffer;
+    assert_eq!(converter.decode_with_length_prefix(&mut span)?, empty);
+    let actual = converter.decode_with_length_prefix(&mut span)?;
+    assert_eq!(actual, source);
+    assert_eq!(actual.as_ptr(), buffer[5..].as_ptr());
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn decode_with_length_prefix_not_enough_bytes() {
+    let converter = BorrowedBytesConverter::new();
+    let buffer = [4u8, 1, 2, 3];
+    let mut span = &buffer[..];
+    let error = converter.decode_with_length_prefix(&mut span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(0));
+}
@@ -0,0 +1 @@
+mod borrowed_bytes_converter;
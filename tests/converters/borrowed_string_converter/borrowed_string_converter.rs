@@ -0,0 +1,91 @@
+use binary::{Converter, allocator::Allocator, converter, converter::BorrowConverter, converters::borrowed_string_converter::BorrowedStringConverter};
+
+fn auto_methods_with_data(source: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = BorrowedStringConverter::new();
+    converter.encode_auto(&mut allocator, &source)?;
+    let mut bytes_expected = vec![0u8; 4];
+    let mut bytes_written = 0usize;
+    converter::encode_direct(&mut bytes_expected, source.len(), &mut bytes_written)?;
+    bytes_expected.resize(bytes_written, 0);
+    bytes_expected.extend_from_slice(source.as_bytes());
+    assert_eq!(allocator[..], bytes_expected[..]);
+    let buffer = &allocator[..];
+    let mut span = buffer;
+    let actual = converter.decode_auto(&mut span)?;
+    assert_eq!(actual, source);
+    assert_eq!(actual.as_ptr(), buffer[bytes_written..].as_ptr());
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn base_info() {
+    let converter = BorrowedStringConverter::new();
+    assert_eq!(converter.length(), 0);
+    assert_eq!(converter.generic_argument(), std::any::TypeId::of::<&'static str>())
+}
+
+#[test]
+fn base_methods() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = BorrowedStringConverter::new();
+    converter.encode(&mut allocator, &"Hello, world!")?;
+    assert_eq!(allocator[..], *b"Hello, world!");
+    let span = &allocator[..];
+    let actual = converter.decode(span)?;
+    assert_eq!(actual, "Hello, world!");
+    assert_eq!(actual.as_ptr(), span.as_ptr());
+    Ok(())
+}
+
+#[test]
+fn auto_methods() -> Result<(), Box<dyn std::error::Error>> {
+    auto_methods_with_data("")?;
+    auto_methods_with_data("Hello, world!")?;
+    auto_methods_with_data(&"Alpha".repeat(100))?;
+    Ok(())
+}
+
+#[test]
+fn decode_outlives_converter() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    BorrowedStringConverter::new().encode_with_length_prefix(&mut allocator, &"Alpha")?;
+    BorrowedStringConverter::new().encode_with_length_prefix(&mut allocator, &"Bravo")?;
+    let mut span = &allocator[..];
+    let head = BorrowedStringConverter::new().decode_with_length_prefix(&mut span)?;
+    let tail = BorrowedStringConverter::new().decode_with_length_prefix(&mut span)?;
+    assert_eq!([head, tail], ["Alpha", "Bravo"]);
+    Ok(())
+}
+
+#[test]
+fn decode_invalid_bytes() {
+    let converter = BorrowedStringConverter::new();
+    let buffer = [0x80u8; 1];
+    let result = converter.decode(&buffer[..]);
+    assert!(matches!(result.unwrap_err(), binary::Error::InvalidUtf8(_)));
+}
+
+#[test]
+fn shared_default_methods() -> Result<(), Box<dyn std::error::Error>> {
+    let converter = BorrowedStringConverter::new();
+    for source in ["", "Alpha", &"Bravo".repeat(30)] {
+        let mut allocator = Allocator::new();
+        converter.encode_with_length_prefix(&mut allocator, &source)?;
+        assert_eq!(converter.encoded_length(&source)?, source.len());
+        assert_eq!(converter.encoded_length_auto(&source)?, allocator.length());
+        assert_eq!(converter.encoded_length_with_length_prefix(&source)?, allocator.length());
+        let mut span = &allocator[..];
+        converter.validate_auto(&mut span)?;
+        assert_eq!(span.len(), 0);
+        let mut span = &allocator[..];
+        converter.validate_with_length_prefix(&mut span)?;
+        assert_eq!(span.len(), 0);
+    }
+    let mut span = &[0x02u8, 0xC3, 0x28][..];
+    let error = converter.validate_with_length_prefix(&mut span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::InvalidUtf8(_)));
+    assert_eq!(error.offset(), Some(1));
+    Ok(())
+}
@@ -0,0 +1 @@
+mod borrowed_string_converter;
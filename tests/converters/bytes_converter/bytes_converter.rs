@@ -0,0 +1,52 @@
+use binary::{Converter, allocator::Allocator, converter, converters::bytes_converter::BytesConverter};
+
+fn auto_methods_with_data<T: std::fmt::Debug + PartialEq + 'static>(converter: &dyn converter::Converter<T>, source: T, expected: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    converter.encode_auto(&mut allocator, &source)?;
+    assert_eq!(allocator[..], *expected);
+    let mut span = &allocator[..];
+    let actual = converter.decode_auto(&mut span)?;
+    assert_eq!(actual, source);
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn base_info() {
+    assert_eq!(BytesConverter::<Vec<u8>>::new().length(), 0);
+    assert_eq!(BytesConverter::<Vec<u8>>::new().generic_argument(), std::any::TypeId::of::<Vec<u8>>());
+    assert_eq!(BytesConverter::<Box<[u8]>>::new().length(), 0);
+    assert_eq!(BytesConverter::<Box<[u8]>>::new().generic_argument(), std::any::TypeId::of::<Box<[u8]>>());
+    assert_eq!(BytesConverter::<[u8; 16]>::new().length(), 16);
+    assert_eq!(BytesConverter::<[u8; 16]>::new().generic_argument(), std::any::TypeId::of::<[u8; 16]>());
+    assert!(BytesConverter::<[u8; 16]>::new().is_zero_sized() == false);
+    assert!(BytesConverter::<[u8; 0]>::new().is_zero_sized());
+}
+
+#[test]
+fn auto_methods() -> Result<(), Box<dyn std::error::Error>> {
+    auto_methods_with_data(&BytesConverter::<Vec<u8>>::new(), vec![], &[0])?;
+    auto_methods_with_data(&BytesConverter::<Vec<u8>>::new(), vec![1, 2, 3], &[3, 1, 2, 3])?;
+    auto_methods_with_data(&BytesConverter::<Box<[u8]>>::new(), Box::from([7u8, 8].as_slice()), &[2, 7, 8])?;
+    auto_methods_with_data(&BytesConverter::<[u8; 4]>::new(), [9, 8, 7, 6], &[9, 8, 7, 6])?;
+    auto_methods_with_data(&BytesConverter::<[u8; 0]>::new(), [], &[])?;
+    Ok(())
+}
+
+#[test]
+fn auto_methods_large() -> Result<(), Box<dyn std::error::Error>> {
+    let source = (0..100_000).map(|x| x as u8).collect::<Vec<u8>>();
+    let mut expected = vec![0x80, 0x01, 0x86, 0xA0];
+    expected.extend_from_slice(&source);
+    auto_methods_with_data(&BytesConverter::<Vec<u8>>::new(), source, &expected)?;
+    Ok(())
+}
+
+#[test]
+fn decode_array_not_enough_bytes() {
+    let converter = Box::new(BytesConverter::<[u8; 4]>::new()) as Box<dyn converter::Converter<[u8; 4]>>;
+    let buffer = [0u8; 3];
+    let span = &buffer[..];
+    let result = converter.decode(&span);
+    assert!(matches!(result.unwrap_err(), binary::Error::NotEnoughBytes));
+}
@@ -0,0 +1 @@
+mod bytes_converter;
@@ -1,5 +1,5 @@
 use std::{
-    collections::{BTreeSet, HashSet},
+    collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque},
     fmt::Debug,
 };
 
@@ -7,7 +7,8 @@ use binary::{
     Converter,
     allocator::Allocator,
     converter,
-    converters::{collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, string_converter::StringConverter},
+    converters::{collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, string_converter::StringConverter, wrapper_converter::WrapperConverter, zero_sized_converter::ZeroSizedConverter},
+    decode_limits::DecodeLimits,
 };
 
 fn base_info_with_data<T: IntoIterator + 'static>(item_converter: Box<dyn converter::Converter<<T as IntoIterator>::Item>>)
@@ -39,6 +40,10 @@ fn base_info() {
     base_info_with_data::<Vec<String>>(Box::new(StringConverter::new()));
     base_info_with_data::<HashSet<i32>>(Box::new(LittleEndianConverter::<i32>::new()));
     base_info_with_data::<BTreeSet<String>>(Box::new(StringConverter::new()));
+    base_info_with_data::<VecDeque<i32>>(Box::new(LittleEndianConverter::<i32>::new()));
+    base_info_with_data::<BinaryHeap<i32>>(Box::new(LittleEndianConverter::<i32>::new()));
+    base_info_with_data::<LinkedList<String>>(Box::new(StringConverter::new()));
+    base_info_with_data::<Box<[i32]>>(Box::new(LittleEndianConverter::<i32>::new()));
 }
 
 #[test]
@@ -47,5 +52,225 @@ fn base_methods() -> Result<(), Box<dyn std::error::Error>> {
     base_methods_with_data::<Vec<String>>(Box::new(StringConverter::new()), &vec!["Alpha".to_string(), "Bravo".to_string()])?;
     base_methods_with_data::<HashSet<i32>>(Box::new(LittleEndianConverter::<i32>::new()), &HashSet::from([1, 2, 3]))?;
     base_methods_with_data::<BTreeSet<String>>(Box::new(StringConverter::new()), &BTreeSet::from(["Alpha".to_string(), "Bravo".to_string()]))?;
+    base_methods_with_data::<VecDeque<i32>>(Box::new(LittleEndianConverter::<i32>::new()), &VecDeque::from([4, 5, 6]))?;
+    base_methods_with_data::<LinkedList<String>>(Box::new(StringConverter::new()), &LinkedList::from(["Alpha".to_string(), "Bravo".to_string()]))?;
+    base_methods_with_data::<Box<[String]>>(Box::new(StringConverter::new()), &Box::from(["Alpha".to_string(), "Bravo".to_string()]))?;
+    base_methods_with_data::<Box<[i32]>>(Box::new(LittleEndianConverter::<i32>::new()), &Box::from([7, 8, 9]))?;
+    base_methods_with_data::<Vec<i32>>(Box::new(LittleEndianConverter::<i32>::new()), &vec![])?;
+    base_methods_with_data::<HashSet<String>>(Box::new(StringConverter::new()), &HashSet::new())?;
+    Ok(())
+}
+
+#[test]
+fn binary_heap_methods() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = CollectionConverter::<BinaryHeap<i32>>::new(Box::new(LittleEndianConverter::<i32>::new()));
+    converter::Converter::encode(&converter, &mut allocator, &BinaryHeap::from([3, 1, 4, 1, 5]))?;
+    assert_eq!(allocator.length(), 20);
+    let span = &allocator[..];
+    let actual = converter::Converter::decode(&converter, &span)?;
+    assert_eq!(actual.into_sorted_vec(), vec![1, 1, 3, 4, 5]);
+    Ok(())
+}
+
+#[test]
+fn decode_unique_elements() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let source = CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()));
+    converter::Converter::encode(&source, &mut allocator, &vec!["Alpha".to_string(), "Bravo".to_string(), "Alpha".to_string()])?;
+    let span = &allocator[..];
+
+    let converter = CollectionConverter::<BTreeSet<String>>::new(Box::new(StringConverter::new()));
+    assert_eq!(converter::Converter::decode(&converter, &span)?.len(), 2);
+    let converter = CollectionConverter::<BTreeSet<String>>::with_unique_elements(Box::new(StringConverter::new()));
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::DuplicateElement));
+    let converter = CollectionConverter::<HashSet<String>>::with_unique_elements(Box::new(StringConverter::new()));
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::DuplicateElement));
+    assert_eq!(error.path(), "[2]");
+    assert_eq!(error.offset(), Some(12));
+
+    let mut allocator = Allocator::new();
+    converter::Converter::encode(&source, &mut allocator, &vec!["Alpha".to_string(), "Bravo".to_string()])?;
+    let span = &allocator[..];
+    let converter = CollectionConverter::<HashSet<String>>::with_unique_elements(Box::new(StringConverter::new()));
+    assert_eq!(converter::Converter::decode(&converter, &span)?, HashSet::from(["Alpha".to_string(), "Bravo".to_string()]));
+    Ok(())
+}
+
+#[test]
+fn decode_nested_error_context() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = CollectionConverter::<Vec<Vec<i32>>>::new(Box::new(CollectionConverter::<Vec<i32>>::new(Box::new(LittleEndianConverter::<i32>::new()))));
+    converter::Converter::encode(&converter, &mut allocator, &vec![vec![1], vec![2, 3, 4]])?;
+    assert_eq!(allocator.length(), 18);
+    assert_eq!(allocator[0], 4);
+    assert_eq!(allocator[5], 12);
+
+    let span = &allocator[..17];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(5));
+    assert_eq!(error.path(), "[1]");
+
+    let mut buffer = allocator[..17].to_vec();
+    buffer[5] = 11;
+    let span = &buffer[..];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(14));
+    assert_eq!(error.path(), "[1][2]");
+    assert_eq!(error.to_string(), "not enough bytes. (offset: 14, path: [1][2])");
+    Ok(())
+}
+
+struct OpaqueConverter<T: 'static> {
+    converter: LittleEndianConverter<T>,
+}
+
+impl<T> binary::Converter for OpaqueConverter<T> {
+    fn length(&self) -> usize {
+        self.converter.length()
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        self.converter.generic_argument()
+    }
+}
+
+impl<T> converter::Converter<T> for OpaqueConverter<T> {
+    fn encode(&self, allocator: &mut Allocator, item: &T) -> Result<(), binary::Error> {
+        self.converter.encode(allocator, item)
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<T, binary::Error> {
+        self.converter.decode(span)
+    }
+}
+
+fn plain_data_methods_with_data<T: IntoIterator + Debug + PartialEq + 'static>(item: &T) -> Result<(), Box<dyn std::error::Error>>
+where
+    T: IntoIterator + FromIterator<<T as IntoIterator>::Item>,
+    for<'a> &'a T: IntoIterator<Item = &'a <T as IntoIterator>::Item>,
+{
+    let plain = CollectionConverter::<T>::new(Box::new(LittleEndianConverter::<<T as IntoIterator>::Item>::new()));
+    let opaque = CollectionConverter::<T>::new(Box::new(OpaqueConverter {
+        converter: LittleEndianConverter::<<T as IntoIterator>::Item>::new(),
+    }));
+    let mut plain_allocator = Allocator::new();
+    let mut opaque_allocator = Allocator::new();
+    converter::Converter::encode_auto(&plain, &mut plain_allocator, item)?;
+    converter::Converter::encode_auto(&opaque, &mut opaque_allocator, item)?;
+    assert_eq!(plain_allocator[..], opaque_allocator[..]);
+    let mut span = &plain_allocator[..];
+    let actual = converter::Converter::decode_auto(&plain, &mut span)?;
+    assert_eq!(&actual, item);
+    assert_eq!(span.len(), 0);
+    let mut span = &plain_allocator[..];
+    let actual = converter::Converter::decode_auto(&opaque, &mut span)?;
+    assert_eq!(&actual, item);
+    Ok(())
+}
+
+#[test]
+fn plain_data_methods() -> Result<(), Box<dyn std::error::Error>> {
+    plain_data_methods_with_data::<Vec<f32>>(&(0..10_000).map(|x| x as f32 * 0.5).collect())?;
+    plain_data_methods_with_data::<Vec<i64>>(&(0..10_000).map(|x| x * 1_000_000_007).collect())?;
+    plain_data_methods_with_data::<Vec<u8>>(&vec![])?;
+    plain_data_methods_with_data::<Box<[i32]>>(&(-8..8).collect())?;
+    plain_data_methods_with_data::<BTreeSet<u16>>(&(0..100).collect())?;
+    Ok(())
+}
+
+#[test]
+fn plain_data_decode_remainder() {
+    let converter = CollectionConverter::<Vec<i32>>::new(Box::new(LittleEndianConverter::<i32>::new()));
+    let buffer = [0u8; 10];
+    let span = &buffer[..];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(8));
+    assert_eq!(error.path(), "[2]");
+}
+
+#[test]
+fn decode_fixed_length_remainder() {
+    let converter = CollectionConverter::<HashSet<i32>>::new(Box::new(LittleEndianConverter::<i32>::new()));
+    let buffer = [0u8; 13];
+    let span = &buffer[..];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(12));
+    assert_eq!(error.path(), "[3]");
+}
+
+#[test]
+fn decode_fixed_length_presized() -> Result<(), Box<dyn std::error::Error>> {
+    let converter = CollectionConverter::<Vec<i32>>::new(Box::new(OpaqueConverter {
+        converter: LittleEndianConverter::<i32>::new(),
+    }));
+    let source = (0..1000).collect::<Vec<i32>>();
+    let mut allocator = Allocator::new();
+    converter::Converter::encode(&converter, &mut allocator, &source)?;
+    let span = &allocator[..];
+    let actual = converter::Converter::decode(&converter, &span)?;
+    assert_eq!(actual, source);
+    assert_eq!(actual.capacity(), source.len());
+    Ok(())
+}
+
+#[test]
+fn zero_sized_elements() -> Result<(), Box<dyn std::error::Error>> {
+    let converter = CollectionConverter::<Vec<()>>::new(Box::new(ZeroSizedConverter::<()>::new()));
+    let mut allocator = Allocator::new();
+    converter::Converter::encode_auto(&converter, &mut allocator, &vec![(); 5])?;
+    converter::Converter::encode_auto(&converter, &mut allocator, &vec![])?;
+    assert_eq!(allocator[..], [1, 5, 1, 0]);
+    let mut span = &allocator[..];
+    assert_eq!(converter::Converter::decode_auto(&converter, &mut span)?, vec![(); 5]);
+    assert_eq!(converter::Converter::decode_auto(&converter, &mut span)?, vec![]);
+    assert_eq!(span.len(), 0);
+
+    let mut allocator = Allocator::new();
+    converter::Converter::encode(&converter, &mut allocator, &vec![(); 1000])?;
+    assert_eq!(allocator[..], [0x80, 0, 0x03, 0xE8]);
+    let span = &allocator[..];
+    assert_eq!(converter::Converter::decode(&converter, &span)?.len(), 1000);
+
+    let span = &allocator[..0];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    Ok(())
+}
+
+#[test]
+fn zero_sized_elements_count_limit() -> Result<(), Box<dyn std::error::Error>> {
+    let converter = CollectionConverter::<Vec<Box<()>>>::new(Box::new(WrapperConverter::<Box<()>>::new(Box::new(ZeroSizedConverter::<()>::new()))));
+    let buffer = [0xFFu8, 0xFF, 0xFF, 0xFF];
+    let limits = DecodeLimits {
+        max_total_allocation: 1024,
+        ..DecodeLimits::new()
+    };
+    let error = limits.scope(|| converter::Converter::decode(&converter, &&buffer[..])).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::LimitExceeded(_)));
+
+    let converter = CollectionConverter::<Vec<()>>::new(Box::new(ZeroSizedConverter::<()>::new()));
+    let buffer = [0x84u8, 0, 0, 0];
+    let limits = DecodeLimits {
+        max_collection_length: 1024,
+        ..DecodeLimits::new()
+    };
+    let error = limits.scope(|| converter::Converter::decode(&converter, &&buffer[..])).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::LimitExceeded(_)));
+    assert_eq!(error.offset(), Some(0));
+    let error = limits.scope(|| converter::Converter::validate(&converter, &&buffer[..])).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::LimitExceeded(_)));
+
+    let source = vec![(); (1 << 20) + 1];
+    let mut allocator = Allocator::new();
+    converter::Converter::encode(&converter, &mut allocator, &source)?;
+    assert_eq!(allocator.length(), 4);
+    assert_eq!(converter::Converter::decode(&converter, &&allocator[..])?, source);
     Ok(())
 }
@@ -1 +1,2 @@
 mod collection_converter;
+mod plain_data_converter;
@@ -0,0 +1,47 @@
+use binary::{allocator::Allocator, converter::Converter, converters::collection_converter::CollectionConverter};
+
+#[repr(transparent)]
+#[derive(Debug, PartialEq)]
+struct Id(u32);
+
+struct IdConverter;
+
+impl binary::Converter for IdConverter {
+    fn length(&self) -> usize {
+        std::mem::size_of::<Id>()
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Id>()
+    }
+}
+
+impl Converter<Id> for IdConverter {
+    unsafe fn is_plain_data(&self) -> bool {
+        cfg!(target_endian = "little")
+    }
+
+    fn encode(&self, allocator: &mut Allocator, item: &Id) -> Result<(), binary::Error> {
+        if cfg!(target_endian = "little") {
+            unreachable!()
+        }
+        allocator.append(&item.0.to_le_bytes())
+    }
+
+    fn decode(&self, span: &&[u8]) -> Result<Id, binary::Error> {
+        if cfg!(target_endian = "little") {
+            unreachable!()
+        }
+        Ok(Id(u32::from_le_bytes(span[..4].try_into().unwrap())))
+    }
+}
+
+#[test]
+fn plain_data_custom_element() -> Result<(), binary::Error> {
+    let converter = CollectionConverter::<Vec<Id>>::new(Box::new(IdConverter));
+    let mut allocator = Allocator::new();
+    converter.encode(&mut allocator, &vec![Id(1), Id(0x0203)])?;
+    assert_eq!(allocator[..], [1, 0, 0, 0, 3, 2, 0, 0]);
+    assert_eq!(converter.decode(&&allocator[..])?, vec![Id(1), Id(0x0203)]);
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use binary::{Converter, allocator::Allocator, converter::BorrowConverter, converters::cow_string_converter::CowStringConverter};
+use std::borrow::Cow;
+
+#[test]
+fn base_info() {
+    let converter = CowStringConverter::new();
+    assert_eq!(converter.length(), 0);
+    assert_eq!(converter.generic_argument(), std::any::TypeId::of::<Cow<'static, str>>())
+}
+
+#[test]
+fn auto_methods() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = CowStringConverter::new();
+    converter.encode_auto(&mut allocator, &Cow::Borrowed("Alpha"))?;
+    converter.encode_auto(&mut allocator, &Cow::Owned("Bravo".to_string()))?;
+    assert_eq!(allocator[..], *b"\x05Alpha\x05Bravo");
+    let buffer = &allocator[..];
+    let mut span = buffer;
+    let head = converter.decode_auto(&mut span)?;
+    let tail = converter.decode_auto(&mut span)?;
+    assert!(matches!(head, Cow::Borrowed("Alpha")));
+    assert!(matches!(tail, Cow::Borrowed("Bravo")));
+    assert_eq!(head.as_ptr(), buffer[1..].as_ptr());
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn decode_invalid_bytes() {
+    let converter = CowStringConverter::new();
+    let buffer = [0xC0u8, 0x80];
+    let result = converter.decode(&buffer[..]);
+    assert!(matches!(result.unwrap_err(), binary::Error::InvalidUtf8(_)));
+}
@@ -0,0 +1 @@
+mod cow_string_converter;
@@ -0,0 +1,41 @@
+use binary::{Converter, allocator::Allocator, converter, converters::duration_converter::DurationConverter};
+use std::time::Duration;
+
+fn auto_methods_with_data(source: Duration, expected: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = Box::new(DurationConverter::new()) as Box<dyn converter::Converter<Duration>>;
+    converter.encode_auto(&mut allocator, &source)?;
+    assert_eq!(allocator[..], *expected);
+    let mut span = &allocator[..];
+    let actual = converter.decode_auto(&mut span)?;
+    assert_eq!(actual, source);
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn base_info() {
+    let converter = DurationConverter::new();
+    assert_eq!(converter.length(), 12);
+    assert_eq!(converter.generic_argument(), std::any::TypeId::of::<Duration>())
+}
+
+#[test]
+fn auto_methods() -> Result<(), Box<dyn std::error::Error>> {
+    auto_methods_with_data(Duration::ZERO, &[0; 12])?;
+    auto_methods_with_data(Duration::new(1, 2), &[1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0])?;
+    let mut expected = u64::MAX.to_le_bytes().to_vec();
+    expected.extend_from_slice(&999_999_999u32.to_le_bytes());
+    auto_methods_with_data(Duration::MAX, &expected)?;
+    Ok(())
+}
+
+#[test]
+fn decode_invalid_nanos() {
+    let converter = DurationConverter::new();
+    let mut buffer = [0u8; 12];
+    buffer[8..].copy_from_slice(&1_000_000_000u32.to_le_bytes());
+    let span = &buffer[..];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error, binary::Error::InvalidValue(_)));
+}
@@ -0,0 +1 @@
+mod duration_converter;
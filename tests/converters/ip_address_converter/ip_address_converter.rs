@@ -0,0 +1,48 @@
+use binary::{
+    Converter,
+    allocator::Allocator,
+    converter,
+    converters::ip_address_converter::{IpAddrConverter, Ipv4AddrConverter, Ipv6AddrConverter},
+};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+fn auto_methods_with_data<T: std::fmt::Debug + PartialEq>(converter: &dyn converter::Converter<T>, source: T, expected: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    converter.encode_auto(&mut allocator, &source)?;
+    assert_eq!(allocator[..], *expected);
+    let mut span = &allocator[..];
+    let actual = converter.decode_auto(&mut span)?;
+    assert_eq!(actual, source);
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn base_info() {
+    assert_eq!(Ipv4AddrConverter::new().length(), 4);
+    assert_eq!(Ipv4AddrConverter::new().generic_argument(), std::any::TypeId::of::<Ipv4Addr>());
+    assert_eq!(Ipv6AddrConverter::new().length(), 16);
+    assert_eq!(Ipv6AddrConverter::new().generic_argument(), std::any::TypeId::of::<Ipv6Addr>());
+    assert_eq!(IpAddrConverter::new().length(), 0);
+    assert_eq!(IpAddrConverter::new().generic_argument(), std::any::TypeId::of::<IpAddr>());
+}
+
+#[test]
+fn auto_methods() -> Result<(), Box<dyn std::error::Error>> {
+    auto_methods_with_data(&Ipv4AddrConverter::new(), Ipv4Addr::new(192, 168, 1, 2), &[192, 168, 1, 2])?;
+    auto_methods_with_data(&Ipv6AddrConverter::new(), Ipv6Addr::LOCALHOST, &Ipv6Addr::LOCALHOST.octets())?;
+    auto_methods_with_data(&IpAddrConverter::new(), IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), &[4, 10, 0, 0, 1])?;
+    let mut expected = vec![16];
+    expected.extend_from_slice(&Ipv6Addr::UNSPECIFIED.octets());
+    auto_methods_with_data(&IpAddrConverter::new(), IpAddr::V6(Ipv6Addr::UNSPECIFIED), &expected)?;
+    Ok(())
+}
+
+#[test]
+fn decode_invalid_length() {
+    let converter = IpAddrConverter::new();
+    let buffer = [0u8; 5];
+    let span = &buffer[..];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error, binary::Error::InvalidValue(_)));
+}
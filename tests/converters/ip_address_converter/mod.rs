@@ -0,0 +1 @@
+mod ip_address_converter;
@@ -78,8 +78,17 @@ fn decode_not_enough_bytes() {
     let buffer = [0u8; 1];
     let span = &buffer[..];
     let result = converter.decode(&span);
-    let binding = result.unwrap_err();
-    let error = binding.downcast_ref::<std::io::Error>().unwrap();
-    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    let error = result.unwrap_err();
+    assert!(matches!(error, binary::Error::NotEnoughBytes));
     assert_eq!(error.to_string(), "not enough bytes.")
 }
+
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq)]
+struct Id(u32);
+
+#[test]
+fn transparent_without_default() -> Result<(), Box<dyn std::error::Error>> {
+    auto_methods_with_data(Id(0x01020304), &[4, 3, 2, 1])?;
+    Ok(())
+}
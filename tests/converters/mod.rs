@@ -1,3 +1,16 @@
+mod borrowed_bytes_converter;
+mod borrowed_string_converter;
+mod bytes_converter;
 mod collection_converter;
+mod cow_string_converter;
+mod duration_converter;
+mod ip_address_converter;
 mod little_endian_converter;
+mod range_converter;
+mod result_converter;
+mod slice_converter;
+mod socket_address_converter;
 mod string_converter;
+mod system_time_converter;
+mod wrapper_converter;
+mod zero_sized_converter;
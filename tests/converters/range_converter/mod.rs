@@ -0,0 +1 @@
+mod range_converter;
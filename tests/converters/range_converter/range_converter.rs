@@ -0,0 +1,53 @@
+use binary::{
+    Converter,
+    allocator::Allocator,
+    converter,
+    converters::{
+        little_endian_converter::LittleEndianConverter,
+        range_converter::{RangeConverter, RangeInclusiveConverter},
+        string_converter::StringConverter,
+    },
+};
+use std::ops::{Range, RangeInclusive};
+
+fn auto_methods_with_data<T: std::fmt::Debug + PartialEq>(converter: &dyn converter::Converter<T>, source: T, expected: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    converter.encode_auto(&mut allocator, &source)?;
+    assert_eq!(allocator[..], *expected);
+    let mut span = &allocator[..];
+    let actual = converter.decode_auto(&mut span)?;
+    assert_eq!(actual, source);
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn base_info() {
+    assert_eq!(RangeConverter::<i32>::new(Box::new(LittleEndianConverter::<i32>::new())).length(), 8);
+    assert_eq!(RangeConverter::<i32>::new(Box::new(LittleEndianConverter::<i32>::new())).generic_argument(), std::any::TypeId::of::<Range<i32>>());
+    assert_eq!(RangeConverter::<String>::new(Box::new(StringConverter::new())).length(), 0);
+    assert_eq!(RangeInclusiveConverter::<u8>::new(Box::new(LittleEndianConverter::<u8>::new())).length(), 2);
+    assert_eq!(
+        RangeInclusiveConverter::<u8>::new(Box::new(LittleEndianConverter::<u8>::new())).generic_argument(),
+        std::any::TypeId::of::<RangeInclusive<u8>>()
+    );
+}
+
+#[test]
+fn auto_methods() -> Result<(), Box<dyn std::error::Error>> {
+    auto_methods_with_data(&RangeConverter::<i16>::new(Box::new(LittleEndianConverter::<i16>::new())), -1..2, &[0xFF, 0xFF, 2, 0])?;
+    auto_methods_with_data(&RangeInclusiveConverter::<u8>::new(Box::new(LittleEndianConverter::<u8>::new())), 3..=9, &[3, 9])?;
+    auto_methods_with_data(&RangeConverter::<String>::new(Box::new(StringConverter::new())), "a".to_string().."b".to_string(), &[4, 1, b'a', 1, b'b'])?;
+    Ok(())
+}
+
+#[test]
+fn decode_error_context() {
+    let converter = RangeConverter::<String>::new(Box::new(StringConverter::new()));
+    let buffer = [1u8, b'a', 2, b'b'];
+    let span = &buffer[..];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(2));
+    assert_eq!(error.path(), "end");
+}
@@ -0,0 +1 @@
+mod result_converter;
@@ -0,0 +1,62 @@
+use binary::{
+    Converter,
+    allocator::Allocator,
+    converter,
+    converters::{little_endian_converter::LittleEndianConverter, result_converter::ResultConverter, string_converter::StringConverter},
+};
+
+fn auto_methods_with_data<T: std::fmt::Debug + PartialEq, E: std::fmt::Debug + PartialEq>(converter: &dyn converter::Converter<Result<T, E>>, source: Result<T, E>, expected: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    converter.encode_auto(&mut allocator, &source)?;
+    assert_eq!(allocator[..], *expected);
+    let mut span = &allocator[..];
+    let actual = converter.decode_auto(&mut span)?;
+    assert_eq!(actual, source);
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn base_info() {
+    let converter = ResultConverter::<String, i32>::new(Box::new(StringConverter::new()), Box::new(LittleEndianConverter::<i32>::new()));
+    assert_eq!(converter.length(), 0);
+    assert_eq!(converter.generic_argument(), std::any::TypeId::of::<Result<String, i32>>());
+    let converter = ResultConverter::<u32, i32>::new(Box::new(LittleEndianConverter::<u32>::new()), Box::new(LittleEndianConverter::<i32>::new()));
+    assert_eq!(converter.length(), 5);
+    let converter = ResultConverter::<u16, i32>::new(Box::new(LittleEndianConverter::<u16>::new()), Box::new(LittleEndianConverter::<i32>::new()));
+    assert_eq!(converter.length(), 0);
+}
+
+#[test]
+fn auto_methods() -> Result<(), Box<dyn std::error::Error>> {
+    let converter = ResultConverter::<String, i32>::new(Box::new(StringConverter::new()), Box::new(LittleEndianConverter::<i32>::new()));
+    auto_methods_with_data(&converter, Ok("Alpha".to_string()), &[7, 0, 5, b'A', b'l', b'p', b'h', b'a'])?;
+    auto_methods_with_data(&converter, Err(-2), &[5, 1, 0xFE, 0xFF, 0xFF, 0xFF])?;
+    let converter = ResultConverter::<u32, i32>::new(Box::new(LittleEndianConverter::<u32>::new()), Box::new(LittleEndianConverter::<i32>::new()));
+    auto_methods_with_data(&converter, Ok(1), &[0, 1, 0, 0, 0])?;
+    auto_methods_with_data(&converter, Err(1), &[1, 1, 0, 0, 0])?;
+    Ok(())
+}
+
+#[test]
+fn decode_invalid_tag() {
+    let converter = ResultConverter::<u8, u8>::new(Box::new(LittleEndianConverter::<u8>::new()), Box::new(LittleEndianConverter::<u8>::new()));
+    let buffer = [2u8, 0];
+    let span = &buffer[..];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error, binary::Error::InvalidValue(_)));
+    let span = &buffer[..0];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error, binary::Error::NotEnoughBytes));
+}
+
+#[test]
+fn decode_error_context() {
+    let converter = ResultConverter::<String, String>::new(Box::new(StringConverter::new()), Box::new(StringConverter::new()));
+    let buffer = [1u8, 3, b'a'];
+    let span = &buffer[..];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(1));
+    assert_eq!(error.path(), "err");
+}
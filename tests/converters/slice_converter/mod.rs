@@ -0,0 +1 @@
+mod slice_converter;
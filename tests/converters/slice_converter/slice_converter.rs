@@ -0,0 +1,52 @@
+use binary::{
+    allocator::Allocator,
+    converter,
+    converters::{collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, slice_converter::SliceConverter, string_converter::StringConverter},
+};
+use std::{fmt::Debug, rc::Rc, sync::Arc};
+
+fn base_methods_with_data<T: std::ops::Deref<Target = [E]> + FromIterator<E> + Debug + PartialEq + 'static, E: 'static>(item_converter: Box<dyn converter::Converter<E>>, item: &T) -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = Box::new(SliceConverter::<T, E>::new(item_converter)) as Box<dyn converter::Converter<T>>;
+    assert_eq!(converter.length(), 0);
+    assert_eq!(converter.generic_argument(), std::any::TypeId::of::<T>());
+    converter.encode_auto(&mut allocator, item)?;
+    let mut span = &allocator[..];
+    let actual = converter.decode_auto(&mut span)?;
+    assert_eq!(&actual, item);
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn base_methods() -> Result<(), Box<dyn std::error::Error>> {
+    base_methods_with_data::<Rc<[i32]>, i32>(Box::new(LittleEndianConverter::<i32>::new()), &Rc::from([1, 2, 3]))?;
+    base_methods_with_data::<Arc<[String]>, String>(Box::new(StringConverter::new()), &Arc::from(["Alpha".to_string(), "Bravo".to_string()]))?;
+    base_methods_with_data::<Box<[i64]>, i64>(Box::new(LittleEndianConverter::<i64>::new()), &Box::from([]))?;
+    base_methods_with_data::<Vec<String>, String>(Box::new(StringConverter::new()), &vec!["Charlie".to_string()])?;
+    Ok(())
+}
+
+#[test]
+fn same_layout_as_collection() -> Result<(), Box<dyn std::error::Error>> {
+    let mut slice_allocator = Allocator::new();
+    let mut collection_allocator = Allocator::new();
+    let source = vec!["Alpha".to_string(), "Bravo".to_string()];
+    let slice = SliceConverter::<Rc<[String]>, String>::new(Box::new(StringConverter::new()));
+    let collection = CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()));
+    converter::Converter::encode(&slice, &mut slice_allocator, &Rc::from(source.clone()))?;
+    converter::Converter::encode(&collection, &mut collection_allocator, &source)?;
+    assert_eq!(slice_allocator[..], collection_allocator[..]);
+    Ok(())
+}
+
+#[test]
+fn decode_not_enough_bytes() {
+    let converter = SliceConverter::<Rc<[i32]>, i32>::new(Box::new(LittleEndianConverter::<i32>::new()));
+    let buffer = [0u8; 6];
+    let span = &buffer[..];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(4));
+    assert_eq!(error.path(), "[1]");
+}
@@ -0,0 +1 @@
+mod socket_address_converter;
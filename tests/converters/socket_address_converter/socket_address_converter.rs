@@ -0,0 +1,59 @@
+use binary::{Converter, allocator::Allocator, converter, converters::socket_address_converter::SocketAddrConverter};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
+
+fn auto_methods_with_data(source: SocketAddr, expected: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = Box::new(SocketAddrConverter::new()) as Box<dyn converter::Converter<SocketAddr>>;
+    converter.encode_auto(&mut allocator, &source)?;
+    assert_eq!(allocator[..], *expected);
+    let mut span = &allocator[..];
+    let actual = converter.decode_auto(&mut span)?;
+    assert_eq!(actual, source);
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn base_info() {
+    let converter = SocketAddrConverter::new();
+    assert_eq!(converter.length(), 0);
+    assert_eq!(converter.generic_argument(), std::any::TypeId::of::<SocketAddr>())
+}
+
+#[test]
+fn auto_methods() -> Result<(), Box<dyn std::error::Error>> {
+    auto_methods_with_data(SocketAddr::from((Ipv4Addr::new(127, 0, 0, 1), 8080)), &[6, 127, 0, 0, 1, 0x90, 0x1F])?;
+    let mut expected = vec![18];
+    expected.extend_from_slice(&Ipv6Addr::LOCALHOST.octets());
+    expected.extend_from_slice(&443u16.to_le_bytes());
+    auto_methods_with_data(SocketAddr::from((Ipv6Addr::LOCALHOST, 443)), &expected)?;
+    Ok(())
+}
+
+#[test]
+fn auto_methods_scoped_ipv6() -> Result<(), Box<dyn std::error::Error>> {
+    let source: SocketAddr = "[fe80::1%3]:80".parse()?;
+    let mut expected = vec![26];
+    expected.extend_from_slice(&"fe80::1".parse::<Ipv6Addr>()?.octets());
+    expected.extend_from_slice(&80u16.to_le_bytes());
+    expected.extend_from_slice(&0u32.to_le_bytes());
+    expected.extend_from_slice(&3u32.to_le_bytes());
+    auto_methods_with_data(source, &expected)?;
+    let source = SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0x12345, 0));
+    let mut expected = vec![26];
+    expected.extend_from_slice(&Ipv6Addr::LOCALHOST.octets());
+    expected.extend_from_slice(&443u16.to_le_bytes());
+    expected.extend_from_slice(&0x12345u32.to_le_bytes());
+    expected.extend_from_slice(&0u32.to_le_bytes());
+    auto_methods_with_data(source, &expected)?;
+    Ok(())
+}
+
+#[test]
+fn decode_invalid_length() {
+    let converter = SocketAddrConverter::new();
+    let buffer = [0u8; 4];
+    let span = &buffer[..];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error, binary::Error::InvalidValue(_)));
+}
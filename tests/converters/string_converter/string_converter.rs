@@ -83,7 +83,7 @@ fn decode_invalid_bytes() {
     let buffer = [0x80u8; 1];
     let span = &buffer[..];
     let result = converter.decode(&span);
-    let binding = result.unwrap_err();
-    let error = binding.downcast_ref::<core::str::Utf8Error>().unwrap();
+    let error = result.unwrap_err();
+    let binary::Error::InvalidUtf8(error) = error else { panic!("unexpected error: {error}") };
     assert_eq!(error.error_len(), Some(1))
 }
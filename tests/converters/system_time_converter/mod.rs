@@ -0,0 +1 @@
+mod system_time_converter;
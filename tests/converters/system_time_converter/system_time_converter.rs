@@ -0,0 +1,53 @@
+use binary::{Converter, allocator::Allocator, converter, converters::system_time_converter::SystemTimeConverter};
+use std::time::{Duration, SystemTime};
+
+fn auto_methods_with_data(source: SystemTime, secs: i64, nanos: u32) -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = Box::new(SystemTimeConverter::new()) as Box<dyn converter::Converter<SystemTime>>;
+    converter.encode_auto(&mut allocator, &source)?;
+    let mut expected = secs.to_le_bytes().to_vec();
+    expected.extend_from_slice(&nanos.to_le_bytes());
+    assert_eq!(allocator[..], expected[..]);
+    let mut span = &allocator[..];
+    let actual = converter.decode_auto(&mut span)?;
+    assert_eq!(actual, source);
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn base_info() {
+    let converter = SystemTimeConverter::new();
+    assert_eq!(converter.length(), 12);
+    assert_eq!(converter.generic_argument(), std::any::TypeId::of::<SystemTime>())
+}
+
+#[test]
+fn auto_methods() -> Result<(), Box<dyn std::error::Error>> {
+    auto_methods_with_data(SystemTime::UNIX_EPOCH, 0, 0)?;
+    auto_methods_with_data(SystemTime::UNIX_EPOCH + Duration::new(1_700_000_000, 123), 1_700_000_000, 123)?;
+    auto_methods_with_data(SystemTime::UNIX_EPOCH - Duration::new(5, 0), -5, 0)?;
+    auto_methods_with_data(SystemTime::UNIX_EPOCH - Duration::new(5, 250_000_000), -6, 750_000_000)?;
+    Ok(())
+}
+
+#[test]
+fn auto_methods_now() -> Result<(), Box<dyn std::error::Error>> {
+    let source = SystemTime::now();
+    let mut allocator = Allocator::new();
+    let converter = SystemTimeConverter::new();
+    converter::Converter::encode_auto(&converter, &mut allocator, &source)?;
+    let mut span = &allocator[..];
+    assert_eq!(converter::Converter::decode_auto(&converter, &mut span)?, source);
+    Ok(())
+}
+
+#[test]
+fn decode_invalid_nanos() {
+    let converter = SystemTimeConverter::new();
+    let mut buffer = [0u8; 12];
+    buffer[8..].copy_from_slice(&u32::MAX.to_le_bytes());
+    let span = &buffer[..];
+    let error = converter::Converter::decode(&converter, &span).unwrap_err();
+    assert!(matches!(error, binary::Error::InvalidValue(_)));
+}
@@ -0,0 +1,94 @@
+use binary::{
+    allocator::Allocator,
+    converter,
+    converters::{
+        collection_converter::CollectionConverter,
+        little_endian_converter::LittleEndianConverter,
+        string_converter::StringConverter,
+        wrapper_converter::{Wrapper, WrapperConverter},
+        zero_sized_converter::ZeroSizedConverter,
+    },
+};
+use std::{
+    borrow::Cow,
+    cmp::Reverse,
+    num::{NonZeroI64, NonZeroU32, Wrapping},
+    rc::Rc,
+    sync::Arc,
+};
+
+fn auto_methods_with_data<T: Wrapper + std::fmt::Debug + PartialEq>(item_converter: Box<dyn converter::Converter<T::Inner>>, source: T) -> Result<(), Box<dyn std::error::Error>> {
+    let mut inner_allocator = Allocator::new();
+    item_converter.encode_auto(&mut inner_allocator, source.inner())?;
+    let length = item_converter.length();
+    let converter = Box::new(WrapperConverter::<T>::new(item_converter)) as Box<dyn converter::Converter<T>>;
+    assert_eq!(converter.length(), length);
+    assert_eq!(converter.generic_argument(), T::generic_argument());
+    let mut allocator = Allocator::new();
+    converter.encode_auto(&mut allocator, &source)?;
+    assert_eq!(allocator[..], inner_allocator[..]);
+    let mut span = &allocator[..];
+    let actual = converter.decode_auto(&mut span)?;
+    assert_eq!(actual, source);
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn auto_methods() -> Result<(), Box<dyn std::error::Error>> {
+    auto_methods_with_data::<Box<i32>>(Box::new(LittleEndianConverter::<i32>::new()), Box::new(-7))?;
+    auto_methods_with_data::<Rc<String>>(Box::new(StringConverter::new()), Rc::new("Alpha".to_string()))?;
+    auto_methods_with_data::<Arc<String>>(Box::new(StringConverter::new()), Arc::new("Bravo".to_string()))?;
+    auto_methods_with_data::<Arc<Vec<i64>>>(Box::new(CollectionConverter::<Vec<i64>>::new(Box::new(LittleEndianConverter::<i64>::new()))), Arc::new(vec![1, 2, 3]))?;
+    auto_methods_with_data::<Cow<'static, String>>(Box::new(StringConverter::new()), Cow::Owned("Charlie".to_string()))?;
+    auto_methods_with_data::<Wrapping<u16>>(Box::new(LittleEndianConverter::<u16>::new()), Wrapping(0xFFFF))?;
+    auto_methods_with_data::<Reverse<u64>>(Box::new(LittleEndianConverter::<u64>::new()), Reverse(42))?;
+    auto_methods_with_data::<NonZeroU32>(Box::new(LittleEndianConverter::<u32>::new()), NonZeroU32::new(9).unwrap())?;
+    auto_methods_with_data::<NonZeroI64>(Box::new(LittleEndianConverter::<i64>::new()), NonZeroI64::new(-1).unwrap())?;
+    Ok(())
+}
+
+#[test]
+fn cow_borrowed() -> Result<(), Box<dyn std::error::Error>> {
+    static SOURCE: i32 = 13;
+    let converter = WrapperConverter::<Cow<'static, i32>>::new(Box::new(LittleEndianConverter::<i32>::new()));
+    let mut allocator = Allocator::new();
+    converter::Converter::encode(&converter, &mut allocator, &Cow::Borrowed(&SOURCE))?;
+    assert_eq!(allocator[..], 13i32.to_le_bytes());
+    Ok(())
+}
+
+#[test]
+fn cow_borrowed_local() -> Result<(), Box<dyn std::error::Error>> {
+    let source = 21i32;
+    let item: Cow<'_, i32> = Cow::Borrowed(&source);
+    let converter = WrapperConverter::<Cow<'_, i32>>::new(Box::new(LittleEndianConverter::<i32>::new()));
+    assert_eq!(binary::Converter::generic_argument(&converter), std::any::TypeId::of::<Cow<'static, i32>>());
+    let mut allocator = Allocator::new();
+    converter::Converter::encode(&converter, &mut allocator, &item)?;
+    assert_eq!(allocator[..], 21i32.to_le_bytes());
+    let result: Cow<'_, i32> = converter::Converter::decode(&converter, &&allocator[..])?;
+    assert!(matches!(result, Cow::Owned(_)));
+    assert_eq!(result, item);
+    Ok(())
+}
+
+#[test]
+fn decode_non_zero_zero() {
+    let converter = WrapperConverter::<NonZeroU32>::new(Box::new(LittleEndianConverter::<u32>::new()));
+    let buffer = [0u8; 4];
+    let mut span = &buffer[..];
+    let error = converter::Converter::decode_auto(&converter, &mut span).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::InvalidValue(_)));
+    assert_eq!(error.root().to_string(), "zero value for non-zero type.");
+}
+
+#[test]
+fn zero_sized_inner() -> Result<(), Box<dyn std::error::Error>> {
+    let converter = WrapperConverter::<Box<()>>::new(Box::new(ZeroSizedConverter::<()>::new()));
+    assert!(binary::Converter::is_zero_sized(&converter));
+    let mut allocator = Allocator::new();
+    converter::Converter::encode_auto(&converter, &mut allocator, &Box::new(()))?;
+    assert_eq!(allocator.length(), 0);
+    Ok(())
+}
@@ -0,0 +1 @@
+mod zero_sized_converter;
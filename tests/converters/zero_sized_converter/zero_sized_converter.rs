@@ -0,0 +1,28 @@
+use binary::{allocator::Allocator, converter, converters::zero_sized_converter::ZeroSizedConverter};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Empty;
+
+fn zero_sized_methods_with_data<T: Eq + Default + std::fmt::Debug + 'static>(source: T) -> Result<(), Box<dyn std::error::Error>> {
+    let converter = Box::new(ZeroSizedConverter::<T>::new()) as Box<dyn converter::Converter<T>>;
+    assert_eq!(converter.length(), 0);
+    assert!(converter.is_zero_sized());
+    assert_eq!(converter.generic_argument(), std::any::TypeId::of::<T>());
+    let mut allocator = Allocator::new();
+    converter.encode_auto(&mut allocator, &source)?;
+    converter.encode_auto(&mut allocator, &source)?;
+    assert_eq!(allocator.length(), 0);
+    let buffer = [1u8, 2, 3];
+    let mut span = &buffer[..];
+    assert_eq!(converter.decode_auto(&mut span)?, source);
+    assert_eq!(span.len(), 3);
+    Ok(())
+}
+
+#[test]
+fn zero_sized_methods() -> Result<(), Box<dyn std::error::Error>> {
+    zero_sized_methods_with_data(())?;
+    zero_sized_methods_with_data(std::marker::PhantomData::<String>)?;
+    zero_sized_methods_with_data(Empty)?;
+    Ok(())
+}
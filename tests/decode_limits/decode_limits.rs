@@ -0,0 +1,118 @@
+use binary::{
+    allocator::Allocator,
+    converter::{self, BorrowConverter, Converter},
+    converters::{
+        borrowed_string_converter::BorrowedStringConverter, bytes_converter::BytesConverter, collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, string_converter::StringConverter,
+        zero_sized_converter::ZeroSizedConverter,
+    },
+    decode_limits::DecodeLimits,
+};
+
+fn encode<T>(converter: &dyn Converter<T>, item: &T) -> Vec<u8> {
+    let mut allocator = Allocator::new();
+    converter.encode(&mut allocator, item).unwrap();
+    allocator[..].to_vec()
+}
+
+fn assert_limit_exceeded<T: std::fmt::Debug>(result: Result<T, binary::Error>, message: &str) {
+    let error = result.unwrap_err();
+    match error.root() {
+        binary::Error::LimitExceeded(actual) => assert_eq!(*actual, message),
+        other => panic!("unexpected error: {other}"),
+    }
+}
+
+#[test]
+fn no_limits_by_default() {
+    let converter = CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()));
+    let buffer = encode(&converter, &vec![String::new(); 10000]);
+    assert_eq!(converter.decode(&buffer.as_slice()).unwrap().len(), 10000);
+    let limits = DecodeLimits::new();
+    assert_eq!(limits.scope(|| converter.decode(&buffer.as_slice())).unwrap().len(), 10000);
+}
+
+#[test]
+fn max_collection_length() {
+    let converter = CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()));
+    let buffer = encode(&converter, &vec![String::new(); 1000]);
+    let limits = DecodeLimits {
+        max_collection_length: 999,
+        ..DecodeLimits::new()
+    };
+    let result = limits.scope(|| converter.decode(&buffer.as_slice()));
+    assert_limit_exceeded(result, "collection length exceeds limit.");
+    let error = limits.scope(|| converter.decode(&buffer.as_slice())).unwrap_err();
+    assert_eq!(error.path(), "[999]");
+    let limits = DecodeLimits {
+        max_collection_length: 1000,
+        ..DecodeLimits::new()
+    };
+    assert_eq!(limits.scope(|| converter.decode(&buffer.as_slice())).unwrap().len(), 1000);
+}
+
+#[test]
+fn max_collection_length_fixed_and_zero_sized() {
+    let limits = DecodeLimits {
+        max_collection_length: 16,
+        ..DecodeLimits::new()
+    };
+    let converter = CollectionConverter::<Vec<i32>>::new(Box::new(LittleEndianConverter::new()));
+    let buffer = encode(&converter, &(0..17).collect());
+    assert_limit_exceeded(limits.scope(|| converter.decode(&buffer.as_slice())), "collection length exceeds limit.");
+    let converter = CollectionConverter::<Vec<()>>::new(Box::new(ZeroSizedConverter::new()));
+    let mut allocator = Allocator::new();
+    converter::encode(&mut allocator, i32::MAX as usize).unwrap();
+    let buffer = allocator[..].to_vec();
+    assert_limit_exceeded(limits.scope(|| converter.decode(&buffer.as_slice())), "collection length exceeds limit.");
+}
+
+#[test]
+fn max_string_length() {
+    let limits = DecodeLimits { max_string_length: 4, ..DecodeLimits::new() };
+    let converter = StringConverter::new();
+    assert_eq!(limits.scope(|| converter.decode(&&b"Alph"[..])).unwrap(), "Alph");
+    assert_limit_exceeded(limits.scope(|| converter.decode(&&b"Alpha"[..])), "string length exceeds limit.");
+    let converter = BorrowedStringConverter::new();
+    assert_limit_exceeded(limits.scope(|| BorrowConverter::decode(&converter, b"Alpha")), "string length exceeds limit.");
+}
+
+#[test]
+fn max_depth() {
+    let converter = CollectionConverter::<Vec<Vec<Vec<String>>>>::new(Box::new(CollectionConverter::<Vec<Vec<String>>>::new(Box::new(CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()))))));
+    let buffer = encode(&converter, &vec![vec![vec!["Alpha".to_string()]]]);
+    let limits = DecodeLimits { max_depth: 3, ..DecodeLimits::new() };
+    assert_eq!(limits.scope(|| converter.decode(&buffer.as_slice())).unwrap().len(), 1);
+    let limits = DecodeLimits { max_depth: 2, ..DecodeLimits::new() };
+    let error = limits.scope(|| converter.decode(&buffer.as_slice())).unwrap_err();
+    assert_eq!(error.path(), "[0][0][0]");
+    assert_limit_exceeded(Err::<(), _>(error), "nesting depth exceeds limit.");
+}
+
+#[test]
+fn max_total_allocation() {
+    let converter = CollectionConverter::<Vec<Vec<u8>>>::new(Box::new(BytesConverter::<Vec<u8>>::new()));
+    let buffer = encode(&converter, &vec![vec![0u8; 100]; 10]);
+    let required = 10 * std::mem::size_of::<Vec<u8>>() + 1000;
+    let limits = DecodeLimits {
+        max_total_allocation: required,
+        ..DecodeLimits::new()
+    };
+    assert_eq!(limits.scope(|| converter.decode(&buffer.as_slice())).unwrap().len(), 10);
+    let limits = DecodeLimits {
+        max_total_allocation: required - 1,
+        ..DecodeLimits::new()
+    };
+    assert_limit_exceeded(limits.scope(|| converter.decode(&buffer.as_slice())), "total allocation exceeds limit.");
+}
+
+#[test]
+fn scope_is_restored() {
+    let converter = StringConverter::new();
+    let outer = DecodeLimits { max_string_length: 4, ..DecodeLimits::new() };
+    let inner = DecodeLimits::new();
+    outer.scope(|| {
+        assert!(inner.scope(|| converter.decode(&&b"Alpha"[..])).is_ok());
+        assert!(converter.decode(&&b"Alpha"[..]).is_err());
+    });
+    assert!(converter.decode(&&b"Alpha"[..]).is_ok());
+}
@@ -0,0 +1,2 @@
+mod decode_limits;
+mod strict_mode;
@@ -0,0 +1,144 @@
+use std::net::SocketAddr;
+
+use binary::{
+    allocator::Allocator,
+    converter::{BorrowConverter, Converter},
+    converters::{
+        borrowed_string_converter::BorrowedStringConverter, collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, range_converter::RangeConverter, result_converter::ResultConverter,
+        socket_address_converter::SocketAddrConverter, string_converter::StringConverter, zero_sized_converter::ZeroSizedConverter,
+    },
+    decode_limits::DecodeLimits,
+    frame_codec::{self, FrameDecoder},
+    stream_decoder::StreamDecoder,
+};
+
+const STRICT: DecodeLimits = DecodeLimits {
+    max_depth: usize::MAX,
+    max_collection_length: usize::MAX,
+    max_string_length: usize::MAX,
+    max_total_allocation: usize::MAX,
+    strict: true,
+};
+
+fn assert_root<T: std::fmt::Debug>(result: Result<T, binary::Error>, expected: &str) {
+    assert_eq!(result.unwrap_err().root().to_string(), expected);
+}
+
+#[test]
+fn strict_round_trip() -> Result<(), binary::Error> {
+    let converter = CollectionConverter::<Vec<String>>::new(Box::new(StringConverter::new()));
+    let item = (0..200).map(|x| "a".repeat(x)).collect::<Vec<_>>();
+    let mut allocator = Allocator::new();
+    converter.encode_with_length_prefix(&mut allocator, &item)?;
+    let mut span = &allocator[..];
+    assert_eq!(STRICT.scope(|| converter.decode_with_length_prefix(&mut span))?, item);
+    let mut span = &allocator[..];
+    assert!(STRICT.scope(|| converter.validate_with_length_prefix(&mut span)).is_ok());
+
+    let converter = SocketAddrConverter::new();
+    let item = "[::1]:443".parse::<SocketAddr>().unwrap();
+    let mut allocator = Allocator::new();
+    converter.encode(&mut allocator, &item)?;
+    assert_eq!(STRICT.scope(|| converter.decode(&&allocator[..]))?, item);
+    Ok(())
+}
+
+#[test]
+fn strict_non_canonical_length_prefix() {
+    let converter = StringConverter::new();
+    let buffer = [0x80, 0x00, 0x00, 0x05, b'A', b'l', b'p', b'h', b'a'];
+    assert_eq!(converter.decode_with_length_prefix(&mut &buffer[..]).unwrap(), "Alpha");
+    assert_root(STRICT.scope(|| converter.decode_with_length_prefix(&mut &buffer[..])), "invalid length prefix.");
+    assert_root(STRICT.scope(|| converter.validate_with_length_prefix(&mut &buffer[..])), "invalid length prefix.");
+    assert_root(STRICT.scope(|| binary::converter::decode(&mut &buffer[..])), "invalid length prefix.");
+    let mut buffer = vec![0x80, 0x00, 0x00, 0x80];
+    buffer.extend_from_slice(&[b'a'; 128]);
+    assert_eq!(STRICT.scope(|| converter.decode_with_length_prefix(&mut &buffer[..])).unwrap().len(), 128);
+}
+
+#[test]
+fn strict_little_endian_extra_bytes() {
+    let converter = LittleEndianConverter::<u16>::new();
+    let buffer = [0x01, 0x02, 0x03];
+    assert_eq!(converter.decode(&&buffer[..]).unwrap(), 0x0201);
+    assert_eq!(STRICT.scope(|| converter.decode(&&buffer[..2])).unwrap(), 0x0201);
+    assert_root(STRICT.scope(|| converter.decode(&&buffer[..])), "trailing bytes.");
+    assert_root(STRICT.scope(|| converter.validate(&&buffer[..])), "trailing bytes.");
+}
+
+#[test]
+fn strict_trailing_bytes_in_body() {
+    let converter = RangeConverter::<i32>::new(Box::new(LittleEndianConverter::new()));
+    let buffer = [0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0xFF];
+    assert_eq!(converter.decode(&&buffer[..]).unwrap(), 1..2);
+    let error = STRICT.scope(|| converter.decode(&&buffer[..])).unwrap_err();
+    assert_eq!(error.offset(), Some(8));
+    assert_root(Err::<(), _>(error), "trailing bytes.");
+    assert_root(STRICT.scope(|| converter.validate(&&buffer[..])), "trailing bytes.");
+
+    let converter = ResultConverter::<String, i32>::new(Box::new(StringConverter::new()), Box::new(LittleEndianConverter::new()));
+    let buffer = [0x00, 0x01, b'A', 0xFF];
+    assert_eq!(converter.decode(&&buffer[..]).unwrap(), Ok("A".to_string()));
+    assert_root(STRICT.scope(|| converter.decode(&&buffer[..])), "trailing bytes.");
+    assert_root(STRICT.scope(|| converter.validate(&&buffer[..])), "trailing bytes.");
+
+    let converter = CollectionConverter::<Vec<()>>::new(Box::new(ZeroSizedConverter::new()));
+    let buffer = [0x03, 0xFF];
+    assert_eq!(converter.decode(&&buffer[..]).unwrap().len(), 3);
+    assert_root(STRICT.scope(|| converter.decode(&&buffer[..])), "trailing bytes.");
+    assert_root(STRICT.scope(|| converter.validate(&&buffer[..])), "trailing bytes.");
+}
+
+#[test]
+fn decode_exact_trailing_bytes() -> Result<(), binary::Error> {
+    let converter = StringConverter::new();
+    let mut allocator = Allocator::new();
+    converter.encode_auto(&mut allocator, &"Alpha".to_string())?;
+    assert_eq!(converter.decode_exact(&allocator[..])?, "Alpha");
+    allocator.append(&[0xFF])?;
+    let mut span = &allocator[..];
+    assert_eq!(STRICT.scope(|| converter.decode_auto(&mut span))?, "Alpha");
+    assert_eq!(span, [0xFF]);
+    let error = converter.decode_exact(&allocator[..]).unwrap_err();
+    assert_eq!(error.offset(), Some(6));
+    assert_root(Err::<(), _>(error), "trailing bytes.");
+
+    let converter = LittleEndianConverter::<u16>::new();
+    assert_eq!(converter.decode_exact(&[0x01, 0x02])?, 0x0201);
+    assert_root(converter.decode_exact(&[0x01, 0x02, 0x03]), "trailing bytes.");
+
+    let converter = BorrowedStringConverter::new();
+    let buffer = [0x05, b'A', b'l', b'p', b'h', b'a'];
+    assert_eq!(converter.decode_exact(&buffer[..])?, "Alpha");
+    assert_root(converter.decode_exact(&[0x05, b'A', b'l', b'p', b'h', b'a', 0x00][..]), "trailing bytes.");
+    Ok(())
+}
+
+#[test]
+fn strict_frame_header() -> Result<(), binary::Error> {
+    let buffer = [0x80, 0x00, 0x00, 0x02, b'h', b'i'];
+    let mut decoder = FrameDecoder::new();
+    decoder.extend(&buffer);
+    assert_eq!(decoder.decode(&StringConverter::new())?, Some("hi".to_string()));
+    let mut decoder = FrameDecoder::with_limits(frame_codec::DEFAULT_MAX_FRAME_LENGTH, STRICT);
+    decoder.extend(&buffer);
+    assert_root(decoder.decode(&StringConverter::new()), "invalid length prefix.");
+    assert_root(decoder.decode_bytes(), "invalid length prefix.");
+
+    let mut decoder = StreamDecoder::new(&buffer[..]);
+    assert_eq!(decoder.decode(&StringConverter::new())?, Some("hi".to_string()));
+    let mut decoder = StreamDecoder::with_limits(&buffer[..], frame_codec::DEFAULT_MAX_FRAME_LENGTH, STRICT);
+    assert_root(decoder.decode(&StringConverter::new()), "invalid length prefix.");
+    Ok(())
+}
+
+#[test]
+fn strict_socket_address_long_form() {
+    let converter = SocketAddrConverter::new();
+    let mut buffer = std::net::Ipv6Addr::LOCALHOST.octets().to_vec();
+    buffer.extend_from_slice(&80u16.to_le_bytes());
+    buffer.extend_from_slice(&[0u8; 8]);
+    let item = "[::1]:80".parse::<SocketAddr>().unwrap();
+    assert_eq!(converter.decode(&&buffer[..]).unwrap(), item);
+    assert_root(STRICT.scope(|| converter.decode(&&buffer[..])), "non-canonical socket address.");
+}
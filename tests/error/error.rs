@@ -0,0 +1,88 @@
+use binary::Error;
+use std::error::Error as _;
+
+#[derive(Debug)]
+struct CustomError;
+
+impl std::fmt::Display for CustomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "custom error.")
+    }
+}
+
+impl std::error::Error for CustomError {}
+
+#[test]
+fn error_display() {
+    assert_eq!(Error::NotEnoughBytes.to_string(), "not enough bytes.");
+    assert_eq!(Error::NotEnoughBytesToWrite.to_string(), "not enough bytes to write.");
+    assert_eq!(Error::MaxCapacityOverflow.to_string(), "maximum capacity has been reached.");
+    assert_eq!(Error::AllocationFailed.to_string(), "out of memory.");
+    assert_eq!(Error::InvalidAllocator.to_string(), "allocator has been modified unexpectedly!");
+    assert_eq!(Error::InvalidLengthPrefix.to_string(), "invalid length prefix.");
+    assert_eq!(Error::TrailingBytes.to_string(), "trailing bytes.");
+}
+
+#[test]
+fn error_custom() {
+    let error = Error::custom(CustomError);
+    assert_eq!(error.to_string(), "custom error.");
+    let Error::Custom(inner) = &error else { panic!("unexpected error: {error}") };
+    assert!(inner.downcast_ref::<CustomError>().is_some());
+    assert!(error.source().unwrap().downcast_ref::<CustomError>().is_some());
+}
+
+#[test]
+fn error_from_message() {
+    let error = Error::from("message");
+    assert!(matches!(error, Error::Custom(_)));
+    assert_eq!(error.to_string(), "message");
+    let error = Error::from(format!("message {}", 1));
+    assert!(matches!(error, Error::Custom(_)));
+    assert_eq!(error.to_string(), "message 1");
+}
+
+#[test]
+fn error_from_utf8() {
+    let bytes = vec![0x80u8];
+    let error = Error::from(String::from_utf8(bytes).unwrap_err().utf8_error());
+    assert!(matches!(error, Error::InvalidUtf8(_)));
+    assert!(error.source().unwrap().downcast_ref::<std::str::Utf8Error>().is_some());
+}
+
+#[test]
+fn error_into_boxed() {
+    let error: Box<dyn std::error::Error + Send + Sync> = Box::new(Error::NotEnoughBytes);
+    assert_eq!(error.to_string(), "not enough bytes.");
+    assert!(matches!(error.downcast_ref::<Error>(), Some(Error::NotEnoughBytes)));
+}
+
+#[test]
+fn error_context_offset() {
+    let error = Error::NotEnoughBytes.with_offset(3).with_offset(4);
+    assert!(matches!(error.root(), Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(7));
+    assert_eq!(error.path(), "");
+    assert_eq!(error.to_string(), "not enough bytes. (offset: 7)");
+    assert!(matches!(error.source().unwrap().downcast_ref::<Error>(), Some(Error::NotEnoughBytes)));
+    assert_eq!(Error::NotEnoughBytes.offset(), None);
+}
+
+#[test]
+fn error_context_path() {
+    let error = Error::NotEnoughBytes.with_field("price").with_offset(2).with_index(3).with_field("items").with_offset(10).with_index(17).with_field("orders");
+    assert!(matches!(error.root(), Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(12));
+    assert_eq!(error.path(), "orders[17].items[3].price");
+    assert_eq!(error.to_string(), "not enough bytes. (offset: 12, path: orders[17].items[3].price)");
+}
+
+#[test]
+fn error_length_prefix_overflow() {
+    let mut allocator = binary::allocator::Allocator::new();
+    let error = binary::converter::encode(&mut allocator, i32::MAX as usize + 1).unwrap_err();
+    assert!(matches!(error, Error::MaxCapacityOverflow));
+    let mut bytes_written = 0;
+    let error = binary::converter::encode_direct(&mut [0u8; 4], i32::MAX as usize + 1, &mut bytes_written).unwrap_err();
+    assert!(matches!(error, Error::MaxCapacityOverflow));
+}
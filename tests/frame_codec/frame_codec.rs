@@ -0,0 +1,94 @@
+use binary::{
+    allocator::Allocator,
+    converters::{collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, string_converter::StringConverter},
+    decode_limits::DecodeLimits,
+    frame_codec::{self, FrameDecoder},
+};
+
+fn encode_frames(items: &[String]) -> Result<Allocator, binary::Error> {
+    let mut allocator = Allocator::new();
+    for item in items {
+        frame_codec::encode_frame(&mut allocator, &StringConverter::new(), item)?;
+    }
+    Ok(allocator)
+}
+
+#[test]
+fn decode_frame_partial() -> Result<(), Box<dyn std::error::Error>> {
+    let allocator = encode_frames(&["Alpha".repeat(10)])?;
+    assert_eq!(allocator.length(), 51);
+    for length in 0..allocator.length() {
+        let mut span = &allocator[..length];
+        assert_eq!(frame_codec::decode_frame(&mut span)?, None);
+        assert_eq!(span.len(), length);
+    }
+    let mut span = &allocator[..];
+    assert_eq!(frame_codec::frame_length(span)?, Some(51));
+    assert_eq!(frame_codec::decode_frame(&mut span)?, Some("Alpha".repeat(10).as_bytes()));
+    assert_eq!(span.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn decoder_byte_by_byte() -> Result<(), Box<dyn std::error::Error>> {
+    let items = vec!["Alpha".to_string(), String::new(), "Bravo".repeat(40)];
+    let allocator = encode_frames(&items)?;
+    let mut decoder = FrameDecoder::new();
+    let converter = StringConverter::new();
+    let mut actual = Vec::new();
+    for byte in allocator[..].iter() {
+        decoder.extend(&[*byte]);
+        while let Some(item) = decoder.decode(&converter)? {
+            actual.push(item);
+        }
+    }
+    assert_eq!(actual, items);
+    assert_eq!(decoder.buffered(), 0);
+    Ok(())
+}
+
+#[test]
+fn decoder_many_frames() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = CollectionConverter::<Vec<i32>>::new(Box::new(LittleEndianConverter::<i32>::new()));
+    for i in 0..10 {
+        frame_codec::encode_frame(&mut allocator, &converter, &(0..i).collect())?;
+    }
+    let mut decoder = FrameDecoder::new();
+    decoder.extend(&allocator[..allocator.length() - 1]);
+    for i in 0..9 {
+        assert_eq!(decoder.decode(&converter)?, Some((0..i).collect()));
+    }
+    assert_eq!(decoder.decode(&converter)?, None);
+    assert_eq!(decoder.buffered(), 36);
+    decoder.extend(&allocator[allocator.length() - 1..]);
+    assert_eq!(decoder.decode(&converter)?, Some((0..9).collect()));
+    assert_eq!(decoder.decode(&converter)?, None);
+    Ok(())
+}
+
+#[test]
+fn decoder_max_frame_length() -> Result<(), Box<dyn std::error::Error>> {
+    let allocator = encode_frames(&["Alpha".to_string(), "Bravo".repeat(10)])?;
+    let mut decoder = FrameDecoder::with_max_frame_length(8);
+    assert_eq!(decoder.max_frame_length(), 8);
+    decoder.extend(&allocator[..10]);
+    assert_eq!(decoder.decode_bytes()?, Some(&b"Alpha"[..]));
+    let error = decoder.decode_bytes().unwrap_err();
+    assert!(matches!(error, binary::Error::LimitExceeded(_)));
+    Ok(())
+}
+
+#[test]
+fn decoder_with_limits() -> Result<(), Box<dyn std::error::Error>> {
+    let allocator = encode_frames(&["Alpha".to_string(), "Charlie".to_string()])?;
+    let limits = DecodeLimits { max_string_length: 5, ..DecodeLimits::new() };
+    let mut decoder = FrameDecoder::with_limits(16, limits);
+    assert_eq!(decoder.max_frame_length(), 16);
+    assert_eq!(decoder.limits(), Some(&limits));
+    decoder.extend(&allocator[..]);
+    assert_eq!(decoder.decode(&StringConverter::new())?, Some("Alpha".to_string()));
+    let error = decoder.decode(&StringConverter::new()).unwrap_err();
+    assert!(matches!(error, binary::Error::LimitExceeded(_)));
+    Ok(())
+}
@@ -0,0 +1 @@
+mod frame_codec;
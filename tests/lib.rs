@@ -1,3 +1,12 @@
 mod allocator;
+mod allocator_pool;
+mod collection_reader;
 mod converter;
 mod converters;
+mod decode_limits;
+mod error;
+mod frame_codec;
+mod stream_decoder;
+mod stream_encoder;
+#[cfg(feature = "tokio")]
+mod tokio_codec;
@@ -0,0 +1 @@
+mod stream_decoder;
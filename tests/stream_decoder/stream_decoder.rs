@@ -0,0 +1,136 @@
+use binary::{
+    allocator::Allocator,
+    converter::Converter,
+    converters::{collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, string_converter::StringConverter},
+    decode_limits::DecodeLimits,
+    stream_decoder::StreamDecoder,
+};
+use std::io::{Cursor, Read};
+
+struct TrickleReader<'a> {
+    span: &'a [u8],
+    interrupted: bool,
+}
+
+impl Read for TrickleReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.interrupted = !self.interrupted;
+        if self.interrupted {
+            return Err(std::io::Error::from(std::io::ErrorKind::Interrupted));
+        }
+        if self.span.is_empty() || buf.is_empty() {
+            return Ok(0);
+        }
+        buf[0] = self.span[0];
+        self.span = &self.span[1..];
+        Ok(1)
+    }
+}
+
+struct BrokenReader;
+
+impl Read for BrokenReader {
+    fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(std::io::ErrorKind::ConnectionReset, "connection reset."))
+    }
+}
+
+fn encode_messages(items: &[String]) -> Result<Allocator, binary::Error> {
+    let mut allocator = Allocator::new();
+    let converter = StringConverter::new();
+    for item in items {
+        converter.encode_with_length_prefix(&mut allocator, item)?;
+    }
+    Ok(allocator)
+}
+
+#[test]
+fn decode_messages() -> Result<(), Box<dyn std::error::Error>> {
+    let items = vec!["Alpha".to_string(), String::new(), "Bravo".repeat(100)];
+    let allocator = encode_messages(&items)?;
+    let mut decoder = StreamDecoder::new(Cursor::new(allocator[..].to_vec()));
+    let converter = StringConverter::new();
+    for item in &items {
+        assert_eq!(decoder.decode(&converter)?.as_ref(), Some(item));
+    }
+    assert_eq!(decoder.decode(&converter)?, None);
+    assert_eq!(decoder.get_ref().position(), allocator.length() as u64);
+    Ok(())
+}
+
+#[test]
+fn decode_messages_trickle() -> Result<(), Box<dyn std::error::Error>> {
+    let mut allocator = Allocator::new();
+    let converter = CollectionConverter::<Vec<i32>>::new(Box::new(LittleEndianConverter::<i32>::new()));
+    converter.encode_with_length_prefix(&mut allocator, &(0..100).collect())?;
+    converter.encode_with_length_prefix(&mut allocator, &vec![7])?;
+    let mut decoder = StreamDecoder::new(TrickleReader { span: &allocator[..], interrupted: false });
+    assert_eq!(decoder.decode(&converter)?, Some((0..100).collect()));
+    assert_eq!(decoder.read_bytes()?, Some(&7i32.to_le_bytes()[..]));
+    assert_eq!(decoder.read_bytes()?, None);
+    Ok(())
+}
+
+#[test]
+fn decode_truncated() -> Result<(), Box<dyn std::error::Error>> {
+    let allocator = encode_messages(&["Alpha".to_string()])?;
+    let mut decoder = StreamDecoder::new(&allocator[..4]);
+    let error = decoder.read_bytes().unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    assert_eq!(error.offset(), Some(4));
+
+    let header = [0x80u8, 0];
+    let mut decoder = StreamDecoder::new(&header[..]);
+    let error = decoder.read_bytes().unwrap_err();
+    assert!(matches!(error.root(), binary::Error::NotEnoughBytes));
+    Ok(())
+}
+
+#[test]
+fn decode_io_error() {
+    let mut decoder = StreamDecoder::new(BrokenReader);
+    let error = decoder.read_bytes().unwrap_err();
+    let binary::Error::Io(error) = error else { panic!("unexpected error: {error}") };
+    assert_eq!(error.kind(), std::io::ErrorKind::ConnectionReset);
+}
+
+#[test]
+fn decode_invalid_payload() {
+    let buffer = [1u8, 0x80];
+    let mut decoder = StreamDecoder::new(&buffer[..]);
+    let error = decoder.decode(&StringConverter::new()).unwrap_err();
+    assert!(matches!(error, binary::Error::InvalidUtf8(_)));
+}
+
+#[test]
+fn decode_max_message_length() -> Result<(), Box<dyn std::error::Error>> {
+    let allocator = encode_messages(&["Alpha".to_string(), "Charlie".to_string()])?;
+    let mut decoder = StreamDecoder::with_max_message_length(&allocator[..], 5);
+    assert_eq!(decoder.max_message_length(), 5);
+    assert_eq!(decoder.decode(&StringConverter::new())?, Some("Alpha".to_string()));
+    let error = decoder.decode(&StringConverter::new()).unwrap_err();
+    assert!(matches!(error, binary::Error::LimitExceeded(_)));
+    Ok(())
+}
+
+#[test]
+fn decode_oversized_header() {
+    let buffer = [0xFFu8, 0xFF, 0xFF, 0xFF];
+    let mut decoder = StreamDecoder::new(&buffer[..]);
+    assert_eq!(decoder.max_message_length(), binary::frame_codec::DEFAULT_MAX_FRAME_LENGTH);
+    let error = decoder.read_bytes().unwrap_err();
+    assert!(matches!(error, binary::Error::LimitExceeded(_)));
+}
+
+#[test]
+fn decode_with_limits() -> Result<(), Box<dyn std::error::Error>> {
+    let allocator = encode_messages(&["Alpha".to_string(), "Charlie".to_string()])?;
+    let limits = DecodeLimits { max_string_length: 5, ..DecodeLimits::new() };
+    let mut decoder = StreamDecoder::with_limits(&allocator[..], 16, limits);
+    assert_eq!(decoder.max_message_length(), 16);
+    assert_eq!(decoder.limits(), Some(&limits));
+    assert_eq!(decoder.decode(&StringConverter::new())?, Some("Alpha".to_string()));
+    let error = decoder.decode(&StringConverter::new()).unwrap_err();
+    assert!(matches!(error, binary::Error::LimitExceeded(_)));
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use binary::{
+    allocator::Allocator,
+    converter::Converter,
+    converters::{collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, string_converter::StringConverter},
+    stream_decoder::StreamDecoder,
+    stream_encoder::StreamEncoder,
+};
+use std::io::Write;
+
+#[derive(Default)]
+struct RecordingWriter {
+    writes: Vec<usize>,
+    bytes: Vec<u8>,
+    flushed: usize,
+}
+
+impl Write for RecordingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writes.push(buf.len());
+        self.bytes.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.flushed += 1;
+        Ok(())
+    }
+}
+
+struct BrokenWriter;
+
+impl Write for BrokenWriter {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "broken pipe."))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+struct FlakyWriter {
+    bytes: Vec<u8>,
+    limit: std::rc::Rc<std::cell::Cell<usize>>,
+}
+
+impl Write for FlakyWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.limit.get() == 0 {
+            return Err(std::io::Error::new(std::io::ErrorKind::WouldBlock, "would block."));
+        }
+        let length = buf.len().min(self.limit.get());
+        self.limit.set(self.limit.get() - length);
+        self.bytes.extend_from_slice(&buf[..length]);
+        Ok(length)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+struct FailingConverter;
+
+impl binary::Converter for FailingConverter {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn generic_argument(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<String>()
+    }
+}
+
+impl Converter<String> for FailingConverter {
+    fn encode(&self, allocator: &mut Allocator, item: &String) -> Result<(), binary::Error> {
+        allocator.append(item.as_bytes())?;
+        Err(binary::Error::from("failed after partial write."))
+    }
+
+    fn decode(&self, _: &&[u8]) -> Result<String, binary::Error> {
+        unreachable!()
+    }
+}
+
+#[test]
+fn encode_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let converter = StringConverter::new();
+    let items = (0..1000).map(|x| format!("item {x}")).collect::<Vec<_>>();
+    let mut encoder = StreamEncoder::new(Vec::new());
+    assert_eq!(encoder.threshold(), 64 * 1024);
+    for item in &items {
+        encoder.encode(&converter, item)?;
+    }
+    let buffer = encoder.into_inner()?;
+    let mut decoder = StreamDecoder::new(&buffer[..]);
+    for item in &items {
+        assert_eq!(decoder.decode(&converter)?.as_ref(), Some(item));
+    }
+    assert_eq!(decoder.decode(&converter)?, None);
+    Ok(())
+}
+
+#[test]
+fn encode_flush_threshold() -> Result<(), Box<dyn std::error::Error>> {
+    let converter = CollectionConverter::<Vec<i32>>::new(Box::new(LittleEndianConverter::<i32>::new()));
+    let mut encoder = StreamEncoder::with_threshold(RecordingWriter::default(), 100);
+    encoder.encode(&converter, &vec![1; 10])?;
+    assert_eq!(encoder.buffered(), 41);
+    encoder.encode(&converter, &vec![2; 10])?;
+    assert_eq!(encoder.buffered(), 82);
+    assert!(encoder.get_ref().writes.is_empty());
+    encoder.encode(&converter, &vec![3; 100])?;
+    assert_eq!(encoder.buffered(), 0);
+    assert_eq!(encoder.get_ref().writes, vec![486]);
+    encoder.encode(&converter, &vec![4; 1])?;
+    encoder.flush()?;
+    assert_eq!(encoder.buffered(), 0);
+    assert_eq!(encoder.get_ref().writes, vec![486, 5]);
+    assert_eq!(encoder.get_ref().flushed, 1);
+    Ok(())
+}
+
+#[test]
+fn encode_flush_on_drop() -> Result<(), Box<dyn std::error::Error>> {
+    let converter = StringConverter::new();
+    let mut buffer = Vec::new();
+    {
+        let mut encoder = StreamEncoder::new(&mut buffer);
+        encoder.encode(&converter, &"Alpha".to_string())?;
+    }
+    assert_eq!(buffer, b"\x05Alpha");
+    Ok(())
+}
+
+#[test]
+fn encode_error_rollback() -> Result<(), Box<dyn std::error::Error>> {
+    let mut encoder = StreamEncoder::new(Vec::new());
+    encoder.encode(&StringConverter::new(), &"Alpha".to_string())?;
+    let result = encoder.encode(&FailingConverter, &"Bravo".to_string());
+    assert!(matches!(result.unwrap_err(), binary::Error::Custom(_)));
+    encoder.encode(&StringConverter::new(), &"Charlie".to_string())?;
+    assert_eq!(encoder.into_inner()?, b"\x05Alpha\x07Charlie");
+    Ok(())
+}
+
+#[test]
+fn encode_io_error() {
+    let mut encoder = StreamEncoder::with_threshold(BrokenWriter, 1);
+    let error = encoder.encode(&StringConverter::new(), &"Alpha".to_string()).unwrap_err();
+    let binary::Error::Io(error) = error else { panic!("unexpected error: {error}") };
+    assert_eq!(error.kind(), std::io::ErrorKind::BrokenPipe);
+}
+
+#[test]
+fn encode_partial_write_resume() -> Result<(), Box<dyn std::error::Error>> {
+    let limit = std::rc::Rc::new(std::cell::Cell::new(4));
+    let writer = FlakyWriter { bytes: Vec::new(), limit: limit.clone() };
+    let mut encoder = StreamEncoder::new(writer);
+    encoder.encode(&StringConverter::new(), &"Alpha".to_string())?;
+    let error = encoder.flush().unwrap_err();
+    let binary::Error::Io(error) = error else { panic!("unexpected error: {error}") };
+    assert_eq!(error.kind(), std::io::ErrorKind::WouldBlock);
+    assert_eq!(encoder.buffered(), 2);
+    assert_eq!(encoder.get_ref().bytes, b"\x05Alp");
+    encoder.encode(&StringConverter::new(), &"Bravo".to_string())?;
+    assert_eq!(encoder.buffered(), 8);
+    limit.set(usize::MAX);
+    let writer = encoder.into_inner()?;
+    assert_eq!(writer.bytes, b"\x05Alpha\x05Bravo");
+    Ok(())
+}
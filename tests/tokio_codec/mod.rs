@@ -0,0 +1 @@
+mod tokio_codec;
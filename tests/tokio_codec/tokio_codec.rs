@@ -0,0 +1,153 @@
+use binary::{
+    converters::{collection_converter::CollectionConverter, little_endian_converter::LittleEndianConverter, string_converter::StringConverter},
+    decode_limits::DecodeLimits,
+    frame_codec::{self, FrameDecoder},
+    tokio_codec::ConverterCodec,
+};
+use bytes::BytesMut;
+use futures_util::{SinkExt, StreamExt};
+use tokio::io::AsyncWriteExt;
+use tokio_util::codec::{Decoder, Encoder, FramedRead, FramedWrite};
+
+#[tokio::test]
+async fn duplex_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let (client, server) = tokio::io::duplex(16);
+    let items = vec!["Alpha".to_string(), String::new(), "Bravo".repeat(100)];
+    let expected = items.clone();
+    let writer = async move {
+        let mut sink = FramedWrite::new(client, ConverterCodec::new(Box::new(StringConverter::new())));
+        for item in items {
+            sink.send(item).await?;
+        }
+        Ok::<_, binary::Error>(())
+    };
+    let reader = async move {
+        let mut stream = FramedRead::new(server, ConverterCodec::new(Box::new(StringConverter::new())));
+        let mut actual = Vec::new();
+        while let Some(item) = stream.next().await {
+            actual.push(item?);
+        }
+        Ok::<_, binary::Error>(actual)
+    };
+    let (written, actual) = tokio::join!(writer, reader);
+    written?;
+    assert_eq!(actual?, expected);
+    Ok(())
+}
+
+#[tokio::test]
+async fn duplex_partial_writes() -> Result<(), Box<dyn std::error::Error>> {
+    let converter = || Box::new(CollectionConverter::<Vec<i32>>::new(Box::new(LittleEndianConverter::<i32>::new())));
+    let mut buffer = BytesMut::new();
+    let mut codec = ConverterCodec::new(converter());
+    for i in 0..10 {
+        codec.encode((0..i).collect(), &mut buffer)?;
+    }
+    let (mut client, server) = tokio::io::duplex(1024);
+    let writer = async move {
+        for byte in buffer.iter() {
+            client.write_all(&[*byte]).await?;
+        }
+        Ok::<_, std::io::Error>(())
+    };
+    let reader = async move {
+        let stream = FramedRead::new(server, ConverterCodec::new(converter()));
+        stream.collect::<Vec<_>>().await
+    };
+    let (written, actual) = tokio::join!(writer, reader);
+    written?;
+    assert_eq!(actual.len(), 10);
+    for (i, item) in actual.into_iter().enumerate() {
+        assert_eq!(item?, (0..i as i32).collect::<Vec<_>>());
+    }
+    Ok(())
+}
+
+#[test]
+fn decode_incomplete_frame() -> Result<(), Box<dyn std::error::Error>> {
+    let mut codec = ConverterCodec::new(Box::new(StringConverter::new()));
+    let mut buffer = BytesMut::new();
+    codec.encode("Alpha".repeat(10), &mut buffer)?;
+    let mut source = BytesMut::new();
+    for byte in buffer.iter().take(buffer.len() - 1) {
+        source.extend_from_slice(&[*byte]);
+        assert_eq!(codec.decode(&mut source)?, None);
+    }
+    assert_eq!(buffer.len(), 51);
+    assert_eq!(source.len(), 50);
+    source.extend_from_slice(&buffer[50..]);
+    assert_eq!(codec.decode(&mut source)?, Some("Alpha".repeat(10)));
+    assert_eq!(source.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn decode_max_frame_length() -> Result<(), Box<dyn std::error::Error>> {
+    let mut codec = ConverterCodec::with_max_frame_length(Box::new(StringConverter::new()), 8);
+    assert_eq!(codec.max_frame_length(), 8);
+    let mut buffer = BytesMut::new();
+    codec.encode("Alpha".to_string(), &mut buffer)?;
+    codec.encode("Bravo".repeat(10), &mut buffer)?;
+    assert_eq!(codec.decode(&mut buffer)?, Some("Alpha".to_string()));
+    let error = codec.decode(&mut buffer).unwrap_err();
+    assert!(matches!(error, binary::Error::LimitExceeded(_)));
+    Ok(())
+}
+
+#[test]
+fn decode_eof_with_trailing_bytes() {
+    let mut codec = ConverterCodec::new(Box::new(StringConverter::new()));
+    let mut buffer = BytesMut::from(&[0x05, b'A', b'l'][..]);
+    let error = codec.decode_eof(&mut buffer).unwrap_err();
+    assert!(matches!(error, binary::Error::Io(_)));
+}
+
+#[test]
+fn decode_oversized_header() {
+    let mut codec = ConverterCodec::new(Box::new(StringConverter::new()));
+    assert_eq!(codec.max_frame_length(), binary::frame_codec::DEFAULT_MAX_FRAME_LENGTH);
+    let mut buffer = BytesMut::from(&[0xFF, 0xFF, 0xFF, 0xFF][..]);
+    let error = codec.decode(&mut buffer).unwrap_err();
+    assert!(matches!(error, binary::Error::LimitExceeded(_)));
+    let mut buffer = BytesMut::from(&[0x80, 0x10, 0x00, 0x00][..]);
+    assert_eq!(codec.decode(&mut buffer).unwrap(), None);
+    assert!(buffer.capacity() < 0x10_0000);
+}
+
+#[test]
+fn decode_with_limits() -> Result<(), Box<dyn std::error::Error>> {
+    let limits = DecodeLimits { max_string_length: 5, ..DecodeLimits::new() };
+    let mut codec = ConverterCodec::with_limits(Box::new(StringConverter::new()), 16, limits);
+    assert_eq!(codec.max_frame_length(), 16);
+    assert_eq!(codec.limits(), Some(&limits));
+    let mut buffer = BytesMut::new();
+    codec.encode("Alpha".to_string(), &mut buffer)?;
+    codec.encode("Charlie".to_string(), &mut buffer)?;
+    assert_eq!(codec.decode(&mut buffer)?, Some("Alpha".to_string()));
+    let error = codec.decode(&mut buffer).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::LimitExceeded(_)));
+    Ok(())
+}
+
+#[test]
+fn decode_with_limits_depth_matches_frame_decoder() -> Result<(), Box<dyn std::error::Error>> {
+    let limits = DecodeLimits { max_depth: 0, ..DecodeLimits::new() };
+    let mut codec = ConverterCodec::with_limits(Box::new(StringConverter::new()), frame_codec::DEFAULT_MAX_FRAME_LENGTH, limits);
+    let mut buffer = BytesMut::new();
+    codec.encode("hi".to_string(), &mut buffer)?;
+    let mut decoder = FrameDecoder::with_limits(frame_codec::DEFAULT_MAX_FRAME_LENGTH, limits);
+    decoder.extend(&buffer);
+    assert_eq!(decoder.decode(&StringConverter::new())?, Some("hi".to_string()));
+    assert_eq!(codec.decode(&mut buffer)?, Some("hi".to_string()));
+    assert_eq!(buffer.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn decode_strict_frame_header() {
+    let limits = DecodeLimits { strict: true, ..DecodeLimits::new() };
+    let mut codec = ConverterCodec::with_limits(Box::new(StringConverter::new()), frame_codec::DEFAULT_MAX_FRAME_LENGTH, limits);
+    let mut buffer = BytesMut::from(&[0x80, 0x00, 0x00, 0x02][..]);
+    let error = codec.decode(&mut buffer).unwrap_err();
+    assert!(matches!(error.root(), binary::Error::InvalidLengthPrefix));
+}